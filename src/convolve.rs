@@ -0,0 +1,248 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::{Complex, DMatrix, RealField};
+use crate::fft::{RealFft, RealFft2D};
+
+/// Which part of the linear convolution to return, following `numpy`/`scipy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConvolveMode {
+    /// The whole convolution, `N + M - 1` samples
+    Full,
+    /// The central `N` samples, i.e. the same length as the first input
+    Same,
+    /// Only the samples that do not depend on zero padding, `max(N, M) - min(N, M) + 1`
+    Valid,
+}
+
+impl ConvolveMode {
+    /// `(start, len)` of the output within the full convolution of lengths `n` and `m`
+    fn range(self, n: usize, m: usize) -> (usize, usize) {
+        if n == 0 || m == 0 {
+            return (0, 0);
+        }
+        match self {
+            ConvolveMode::Full => (0, n + m - 1),
+            ConvolveMode::Same => ((m - 1) / 2, n),
+            ConvolveMode::Valid => (n.min(m) - 1, n.max(m) - n.min(m) + 1),
+        }
+    }
+}
+
+
+/// Linear convolution of `a` and `b`, computed with a zero-padded real FFT.
+///
+/// The result is empty if either input is empty.
+pub fn convolve<T: RealField + Copy>(a: &[T], b: &[T], mode: ConvolveMode) -> Vec<T> {
+    let (start, len) = mode.range(a.len(), b.len());
+    if len == 0 {
+        return Vec::new();
+    }
+    let size = (a.len() + b.len() - 1).next_power_of_two();
+    let fft = RealFft::new(size);
+    let spectrum = |x: &[T]| {
+        let mut buf = vec![T::zero(); size];
+        buf[..x.len()].copy_from_slice(x);
+        fft.rfft(&buf)
+    };
+    let product: Vec<Complex<T>> = spectrum(a).iter().zip(spectrum(b).iter()).map(|(x, y)| x * y).collect();
+
+    let mut full = fft.irfft(&product);
+    full.drain(..start);
+    full.truncate(len);
+    full
+}
+
+
+/// Cross-correlation `c(k) = Σ_n a(n + k) b(n)`, i.e. `convolve(a, reversed b)`.
+///
+/// In `Full` mode the first output is the lag `k = -(M - 1)`.
+pub fn correlate<T: RealField + Copy>(a: &[T], b: &[T], mode: ConvolveMode) -> Vec<T> {
+    let reversed: Vec<T> = b.iter().rev().copied().collect();
+    convolve(a, &reversed, mode)
+}
+
+
+/// Two-dimensional linear convolution; the mode is applied to each axis separately
+pub fn convolve_2d<T: RealField + Copy>(a: &DMatrix<T>, b: &DMatrix<T>, mode: ConvolveMode) -> DMatrix<T> {
+    let (row_start, rows) = mode.range(a.nrows(), b.nrows());
+    let (col_start, cols) = mode.range(a.ncols(), b.ncols());
+    if rows == 0 || cols == 0 {
+        return DMatrix::zeros(rows, cols);
+    }
+    let size_row = (a.nrows() + b.nrows() - 1).next_power_of_two();
+    let size_col = (a.ncols() + b.ncols() - 1).next_power_of_two();
+    let fft = RealFft2D::new(size_row, size_col);
+    let spectrum = |x: &DMatrix<T>| {
+        let mut buf = DMatrix::zeros(size_row, size_col);
+        buf.view_mut((0, 0), x.shape()).copy_from(x);
+        fft.rfft_2d(&buf)
+    };
+
+    let full = fft.irfft_2d(&spectrum(a).zip_map(&spectrum(b), |x, y| x * y));
+    full.view((row_start, col_start), (rows, cols)).into_owned()
+}
+
+
+/// Two-dimensional cross-correlation, i.e. `convolve_2d` with `b` flipped along both axes
+pub fn correlate_2d<T: RealField + Copy>(a: &DMatrix<T>, b: &DMatrix<T>, mode: ConvolveMode) -> DMatrix<T> {
+    let (rows, cols) = b.shape();
+    let flipped = DMatrix::from_fn(rows, cols, |i, j| b[(rows - 1 - i, cols - 1 - j)]);
+    convolve_2d(a, &flipped, mode)
+}
+
+
+/// Regroups input of any length into fixed blocks, and hands back as many output
+/// samples as were put in. The output starts with one block of zeros, which is the latency.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Blocks<T> {
+    block_size: usize,
+    input: VecDeque<T>,
+    output: VecDeque<T>,
+}
+
+impl<T: RealField + Copy> Blocks<T> {
+    fn new(block_size: usize) -> Self {
+        assert!(block_size > 0, "block size must be positive");
+        Self {
+            block_size,
+            input: VecDeque::with_capacity(block_size),
+            output: (0..block_size).map(|_| T::zero()).collect(),
+        }
+    }
+
+    fn process(&mut self, data: &[T], mut filter: impl FnMut(&[T]) -> Vec<T>) -> Vec<T> {
+        self.input.extend(data.iter().copied());
+        while self.input.len() >= self.block_size {
+            let block: Vec<T> = self.input.drain(..self.block_size).collect();
+            self.output.extend(filter(&block));
+        }
+        // 输出队列里始终比已读入的输入多出至少一块，所以这里不会越界
+        self.output.drain(..data.len()).collect()
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.block_size);
+    }
+}
+
+/// Spectrum of `kernel` zero-padded to the plan size
+fn kernel_spectrum<T: RealField + Copy>(fft: &RealFft<T>, kernel: &[T]) -> Vec<Complex<T>> {
+    let mut buf = vec![T::zero(); fft.len()];
+    buf[..kernel.len()].copy_from_slice(kernel);
+    fft.rfft(&buf)
+}
+
+
+/// Streaming FIR filter using the overlap-add method.
+///
+/// Input of any length is accepted by `process`, which returns exactly as many samples;
+/// the output is the full convolution with the kernel, delayed by `latency()` samples.
+/// Each block of `B` samples is zero-padded to a power of two `≥ B + M - 1`, filtered
+/// by FFT, and the `M - 1` sample tail is added onto the following blocks.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlapAdd<T: RealField = f32> {
+    blocks: Blocks<T>,
+    fft: RealFft<T>,
+    kernel: Vec<Complex<T>>,
+    /// 尚未输出的累加结果，长度等于 FFT 长度
+    accumulator: Vec<T>,
+}
+
+impl<T: RealField + Copy> OverlapAdd<T> {
+    /// Plan a filter with the impulse response `kernel`, processing `block_size` samples per FFT
+    pub fn new(kernel: &[T], block_size: usize) -> Self {
+        assert!(!kernel.is_empty(), "kernel must not be empty");
+        let fft = RealFft::new((block_size + kernel.len() - 1).next_power_of_two());
+        Self {
+            blocks: Blocks::new(block_size),
+            kernel: kernel_spectrum(&fft, kernel),
+            accumulator: vec![T::zero(); fft.len()],
+            fft,
+        }
+    }
+
+    /// Delay between input and output, in samples
+    pub fn latency(&self) -> usize {
+        self.blocks.block_size
+    }
+
+    /// Filter the next piece of the stream
+    pub fn process(&mut self, input: &[T]) -> Vec<T> {
+        let (fft, kernel, accumulator) = (&self.fft, &self.kernel, &mut self.accumulator);
+        self.blocks.process(input, |block| {
+            let mut buf = vec![T::zero(); fft.len()];
+            buf[..block.len()].copy_from_slice(block);
+            let spectrum: Vec<Complex<T>> = fft.rfft(&buf).iter().zip(kernel).map(|(x, k)| x * k).collect();
+            for (acc, v) in accumulator.iter_mut().zip(fft.irfft(&spectrum)) {
+                *acc += v;
+            }
+            // 前 B 个结果已经完整，输出后左移
+            let res = accumulator[..block.len()].to_vec();
+            accumulator.rotate_left(block.len());
+            let len = accumulator.len();
+            accumulator[len - block.len()..].iter_mut().for_each(|v| *v = T::zero());
+            res
+        })
+    }
+
+    /// Clear the internal state, as if no input had been seen
+    pub fn reset(&mut self) {
+        self.blocks.reset();
+        self.accumulator.iter_mut().for_each(|v| *v = T::zero());
+    }
+}
+
+
+/// Streaming FIR filter using the overlap-save method.
+///
+/// Same interface and output as `OverlapAdd`. Each FFT covers the newest `B` samples
+/// together with the preceding history, and only the last `B` outputs, which are free
+/// of circular wrap-around, are kept.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlapSave<T: RealField = f32> {
+    blocks: Blocks<T>,
+    fft: RealFft<T>,
+    kernel: Vec<Complex<T>>,
+    /// 最近的输入，长度等于 FFT 长度
+    history: Vec<T>,
+}
+
+impl<T: RealField + Copy> OverlapSave<T> {
+    /// Plan a filter with the impulse response `kernel`, processing `block_size` samples per FFT
+    pub fn new(kernel: &[T], block_size: usize) -> Self {
+        assert!(!kernel.is_empty(), "kernel must not be empty");
+        let fft = RealFft::new((block_size + kernel.len() - 1).next_power_of_two());
+        Self {
+            blocks: Blocks::new(block_size),
+            kernel: kernel_spectrum(&fft, kernel),
+            history: vec![T::zero(); fft.len()],
+            fft,
+        }
+    }
+
+    /// Delay between input and output, in samples
+    pub fn latency(&self) -> usize {
+        self.blocks.block_size
+    }
+
+    /// Filter the next piece of the stream
+    pub fn process(&mut self, input: &[T]) -> Vec<T> {
+        let (fft, kernel, history) = (&self.fft, &self.kernel, &mut self.history);
+        self.blocks.process(input, |block| {
+            history.rotate_left(block.len());
+            let len = history.len();
+            history[len - block.len()..].copy_from_slice(block);
+            let spectrum: Vec<Complex<T>> = fft.rfft(history).iter().zip(kernel).map(|(x, k)| x * k).collect();
+            // 前 N - B 个结果受循环卷积混叠影响，丢弃
+            fft.irfft(&spectrum).split_off(len - block.len())
+        })
+    }
+
+    /// Clear the internal state, as if no input had been seen
+    pub fn reset(&mut self) {
+        self.blocks.reset();
+        self.history.iter_mut().for_each(|v| *v = T::zero());
+    }
+}
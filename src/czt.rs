@@ -0,0 +1,134 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, try_convert, Complex, ComplexField, RealField};
+use crate::fft::Fft;
+
+/// `r^t e^{iθt}`, evaluated in `f64` before rounding to `T`
+fn polar_pow<T: RealField + Copy>(r: f64, theta: f64, t: f64) -> Complex<T> {
+    let mag = r.powf(t);
+    let angle = (theta * t) % (2.0 * PI);
+    Complex::new(convert(mag * angle.cos()), convert(mag * angle.sin()))
+}
+
+fn to_polar<T: RealField + Copy>(z: Complex<T>) -> (f64, f64) {
+    let r = try_convert::<T, f64>(z.modulus()).unwrap();
+    let theta = try_convert::<T, f64>(z.argument()).unwrap();
+    (r, theta)
+}
+
+
+/// Chirp-Z transform plan.
+///
+/// Evaluates the z-transform of an `N`-point signal at the `M` points `z_k = A W^{-k}`,
+/// which lie on a spiral (or, for `|A| = |W| = 1`, an arc of the unit circle).
+/// The sum is computed with Bluestein's identity `nk = (n² + k² - (k - n)²) / 2`
+/// as a convolution of length `≥ N + M - 1`, so it costs a few power-of-two FFTs.
+///
+/// Formula:
+/// ```latex
+/// X(k) = \sum_{n=0}^{N-1} x(n) A^{-n} W^{nk}, \quad k = 0, \dots, M - 1
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Czt<T: RealField = f32> {
+    n: usize,
+    m: usize,
+    w: Complex<T>,
+    a: Complex<T>,
+    fft: Fft<T>,
+    /// `A^{-n} W^{n²/2}`
+    pre: Vec<Complex<T>>,
+    /// FFT of `W^{-j²/2}`, arranged for a circular convolution
+    kernel: Vec<Complex<T>>,
+    /// `W^{k²/2}`
+    post: Vec<Complex<T>>,
+}
+
+impl<T: RealField + Copy> Czt<T> {
+    /// Plan a transform of `n` input samples to `m` output points `z_k = a·w^{-k}`
+    pub fn new(n: usize, m: usize, w: Complex<T>, a: Complex<T>) -> Self {
+        let (rw, tw) = to_polar(w);
+        let (ra, ta) = to_polar(a);
+        Self::from_polar(n, m, (rw, tw), (ra, ta))
+    }
+
+    /// Plan a zoomed spectrum: `m` equally spaced frequencies from `f_start` to `f_end` (both included),
+    /// for a signal of `n` samples taken at rate `fs`
+    pub fn zoom(n: usize, f_start: T, f_end: T, m: usize, fs: T) -> Self {
+        let f_start = try_convert::<T, f64>(f_start).unwrap();
+        let f_end = try_convert::<T, f64>(f_end).unwrap();
+        let fs = try_convert::<T, f64>(fs).unwrap();
+        let step = if m > 1 { (f_end - f_start) / (m - 1) as f64 } else { 0.0 };
+        Self::from_polar(n, m, (1.0, -2.0 * PI * step / fs), (1.0, 2.0 * PI * f_start / fs))
+    }
+
+    fn from_polar(n: usize, m: usize, (rw, tw): (f64, f64), (ra, ta): (f64, f64)) -> Self {
+        let len = (n + m).saturating_sub(1).max(1).next_power_of_two();
+        let fft = Fft::new(len);
+
+        let pre: Vec<Complex<T>> = (0..n)
+            .map(|i| {
+                let i = i as f64;
+                polar_pow::<T>(ra, ta, -i) * polar_pow::<T>(rw, tw, i * i / 2.0)
+            })
+            .collect();
+        let post: Vec<Complex<T>> = (0..m).map(|k| polar_pow(rw, tw, (k * k) as f64 / 2.0)).collect();
+
+        // k - n 的取值范围是 -(N-1)..M，负下标绕到数组末尾
+        let mut kernel = vec![Complex::new(T::zero(), T::zero()); len];
+        for (j, v) in kernel.iter_mut().enumerate().take(m) {
+            *v = polar_pow(rw, tw, -((j * j) as f64) / 2.0);
+        }
+        for j in 1..n {
+            kernel[len - j] = polar_pow(rw, tw, -((j * j) as f64) / 2.0);
+        }
+        fft.fft_inplace(&mut kernel);
+
+        Self {
+            n,
+            m,
+            w: polar_pow(rw, tw, 1.0),
+            a: polar_pow(ra, ta, 1.0),
+            fft,
+            pre,
+            kernel,
+            post,
+        }
+    }
+
+
+    /// The points `z_k = A W^{-k}` at which the transform is evaluated
+    pub fn points(&self) -> Vec<Complex<T>> {
+        let w_inv = Complex::new(T::one(), T::zero()) / self.w;
+        let mut z = self.a;
+        (0..self.m)
+            .map(|_| {
+                let res = z;
+                z *= w_inv;
+                res
+            })
+            .collect()
+    }
+
+
+    pub fn czt(&self, data: &[Complex<T>]) -> Vec<Complex<T>> {
+        assert_eq!(data.len(), self.n, "input length must equal the plan size");
+        let mut buf = vec![Complex::new(T::zero(), T::zero()); self.fft.len()];
+        for (b, (x, p)) in buf.iter_mut().zip(data.iter().zip(self.pre.iter())) {
+            *b = *x * *p;
+        }
+        self.fft.fft_inplace(&mut buf);
+        buf.iter_mut().zip(self.kernel.iter()).for_each(|(b, k)| *b *= *k);
+        self.fft.ifft_inplace(&mut buf);
+        buf.iter().zip(self.post.iter()).map(|(b, p)| *b * *p).collect()
+    }
+}
+
+
+/// Spectrum of `signal` at `m` equally spaced frequencies from `f_start` to `f_end` (both included).
+///
+/// Equivalent to the DTFT sampled on that band, without zero-padding a huge FFT.
+/// Build a `Czt::zoom` plan instead when many signals of the same length are processed.
+pub fn zoom_fft<T: RealField + Copy>(signal: &[Complex<T>], f_start: T, f_end: T, m: usize, fs: T) -> Vec<Complex<T>> {
+    Czt::zoom(signal.len(), f_start, f_end, m, fs).czt(signal)
+}
@@ -1,11 +1,24 @@
-use std::f32::consts::PI;
-use nalgebra::{DMatrix, Dyn, Matrix1x4, Matrix4, Matrix4x1, OMatrix, U4};
+use alloc::vec::Vec;
+use nalgebra::{convert, Complex, DMatrix, Dyn, Matrix1x4, Matrix4, Matrix4x1, OMatrix, RealField, U4};
+use crate::extension::{pad2, ExtensionMode};
 
-fn generate_cosine_table(size: usize) -> Vec<f32> {
+fn cosine<T: RealField + Copy>(x: usize, u: usize, size: usize) -> T {
+    (T::pi() * convert(x as f64 + 0.5) * convert(u as f64) / convert(size as f64)).cos()
+}
+
+fn alpha<T: RealField + Copy>(u: usize, size: usize) -> T {
+    if u == 0 {
+        (T::one() / convert(size as f64)).sqrt()
+    } else {
+        (convert::<f64, T>(2.0) / convert(size as f64)).sqrt()
+    }
+}
+
+fn generate_cosine_table<T: RealField + Copy>(size: usize) -> Vec<T> {
     let mut data = Vec::with_capacity(size * size);
     for x in 0..size {
         for u in 0..size {
-            data.push((PI * (x as f32 + 0.5) * u as f32 / size as f32).cos())
+            data.push(cosine(x, u, size))
         }
     }
     data
@@ -13,27 +26,49 @@ fn generate_cosine_table(size: usize) -> Vec<f32> {
     // DMatrix::from_row_slice(size, size, &data)
 }
 
-fn generate_alpha_table(size: usize) -> Vec<f32> {
-    let mut data = Vec::with_capacity(size);
-    for u in 0..size {
-        if u == 0 {
-            data.push((1.0 / size as f32).sqrt());
-        } else {
-            data.push((2.0 / size as f32).sqrt());
-        }
-    }
-    data
+fn generate_alpha_table<T: RealField + Copy>(size: usize) -> Vec<T> {
+    (0..size).map(|u| alpha(u, size)).collect()
     // DMatrix::from_vec(1, size, data)
 }
 
 
-pub struct Dct {
-    cosine_table: OMatrix<f32, Dyn, Dyn>,
-    alpha_table: OMatrix<f32, Dyn, Dyn>,
-    cosine_table_transpose: OMatrix<f32, Dyn, Dyn>,
+/// 实部在上、虚部在下，堆叠成 2M×N 的实矩阵
+pub(crate) fn split_rows<T: RealField + Copy>(data: &DMatrix<Complex<T>>) -> DMatrix<T> {
+    let (m, n) = data.shape();
+    DMatrix::from_fn(2 * m, n, |i, j| if i < m { data[(i, j)].re } else { data[(i - m, j)].im })
 }
 
-impl Dct {
+/// `split_rows` 的逆操作
+pub(crate) fn merge_rows<T: RealField + Copy>(data: &DMatrix<T>) -> DMatrix<Complex<T>> {
+    let (m, n) = (data.nrows() / 2, data.ncols());
+    DMatrix::from_fn(m, n, |i, j| Complex::new(data[(i, j)], data[(i + m, j)]))
+}
+
+/// 实部在左、虚部在右，拼接成 M×2N 的实矩阵
+pub(crate) fn split_cols<T: RealField + Copy>(data: &DMatrix<Complex<T>>) -> DMatrix<T> {
+    let (m, n) = data.shape();
+    DMatrix::from_fn(m, 2 * n, |i, j| if j < n { data[(i, j)].re } else { data[(i, j - n)].im })
+}
+
+/// `split_cols` 的逆操作
+pub(crate) fn merge_cols<T: RealField + Copy>(data: &DMatrix<T>) -> DMatrix<Complex<T>> {
+    let (m, n) = (data.nrows(), data.ncols() / 2);
+    DMatrix::from_fn(m, n, |i, j| Complex::new(data[(i, j)], data[(i, j + n)]))
+}
+
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Dct<T: RealField = f32> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) cosine_table: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) alpha_table: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    cosine_table_transpose: OMatrix<T, Dyn, Dyn>,
+}
+
+impl<T: RealField + Copy> Dct<T> {
     pub fn new(size: usize) -> Self {
         let cosine_table = DMatrix::from_row_slice(size, size, &generate_cosine_table(size));
         let alpha_table = DMatrix::from_vec(1, size, generate_alpha_table(size));
@@ -57,8 +92,7 @@ impl Dct {
     ///     \sqrt{\frac{2}{N}} & \text{otherwise}
     /// \end{cases}
     /// ```
-
-    pub fn dct_1d(&self, data: &OMatrix<f32, Dyn, Dyn>) -> OMatrix<f32, Dyn, Dyn> {
+    pub fn dct_1d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
         (data * &self.cosine_table).component_mul(&self.alpha_table)
     }
 
@@ -75,22 +109,50 @@ impl Dct {
     ///     \sqrt{\frac{2}{N}} & \text{otherwise}
     /// \end{cases}
     /// ```
-    pub fn idct_1d(&self, data: &OMatrix<f32, Dyn, Dyn>) -> OMatrix<f32, Dyn, Dyn> {
+    pub fn idct_1d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
         (data.component_mul(&self.alpha_table)) * &self.cosine_table_transpose
     }
-}
 
 
-pub struct Dct2D {
-    cosine_table_row: OMatrix<f32, Dyn, Dyn>,
-    alpha_table_row: OMatrix<f32, Dyn, Dyn>,
-    cosine_table_transpose_row: OMatrix<f32, Dyn, Dyn>,
+    /// DCT-II of every row of a complex matrix.
+    ///
+    /// The real and imaginary parts go through the cosine table in a single product.
+    pub fn dct_1d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let mut res = merge_rows(&(split_rows(data) * &self.cosine_table));
+        for (j, mut col) in res.column_iter_mut().enumerate() {
+            let a = self.alpha_table[(0, j)];
+            col.iter_mut().for_each(|v| *v *= a);
+        }
+        res
+    }
+
+
+    /// IDCT-III of every row of a complex matrix
+    pub fn idct_1d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let weighted = DMatrix::from_fn(data.nrows(), data.ncols(), |i, j| data[(i, j)] * self.alpha_table[(0, j)]);
+        merge_rows(&(split_rows(&weighted) * &self.cosine_table_transpose))
+    }
+}
 
-    cosine_table_col: OMatrix<f32, Dyn, Dyn>,
-    cosine_table_transpose_col: OMatrix<f32, Dyn, Dyn>,
-    alpha_table_transpose_col: OMatrix<f32, Dyn, Dyn>,
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Dct2D<T: RealField = f32> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) cosine_table_row: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) alpha_table_row: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    cosine_table_transpose_row: OMatrix<T, Dyn, Dyn>,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) cosine_table_col: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    cosine_table_transpose_col: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) alpha_table_transpose_col: OMatrix<T, Dyn, Dyn>,
 }
-impl Dct2D {
+impl<T: RealField + Copy> Dct2D<T> {
     pub fn new(row: usize, col: usize) -> Self {
         let cosine_table_row = DMatrix::from_row_slice(col, col, &generate_cosine_table(col));
         let alpha_table_row = DMatrix::from_vec(1, col, generate_alpha_table(col));
@@ -98,8 +160,8 @@ impl Dct2D {
         let alpha_table_col = DMatrix::from_vec(1, row, generate_alpha_table(row));
 
         // repeat
-        let alpha_table_row = DMatrix::from_element(row, 1, 1.0) * alpha_table_row;
-        let alpha_table_col = DMatrix::from_element(col, 1, 1.0) * alpha_table_col;
+        let alpha_table_row = DMatrix::from_element(row, 1, T::one()) * alpha_table_row;
+        let alpha_table_col = DMatrix::from_element(col, 1, T::one()) * alpha_table_col;
 
         Self {
             cosine_table_row: cosine_table_row.clone(),
@@ -114,25 +176,115 @@ impl Dct2D {
     }
 
     /// Two-dimensional Discrete Cosine Transform（DCT-II）
-    pub fn dct_2d(&self, data: &OMatrix<f32, Dyn, Dyn>) -> OMatrix<f32, Dyn, Dyn> {
+    pub fn dct_2d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
         // 对每行做 dct
         let tmp = (data * &self.cosine_table_row).component_mul(&self.alpha_table_row);
         // 对每列做dct
-        (&self.cosine_table_transpose_col * tmp).component_mul(&self.alpha_table_transpose_col)
+        (&self.cosine_table_transpose_col * &tmp).component_mul(&self.alpha_table_transpose_col)
     }
 
 
     /// Two-dimensional Inverse Discrete Cosine Transform（IDCT-III）
-    pub fn idct_2d(&self, data: &OMatrix<f32, Dyn, Dyn>) -> OMatrix<f32, Dyn, Dyn> {
+    pub fn idct_2d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
         //     对每一列做 idct
-        let tmp = &self.cosine_table_col * (data.component_mul(&self.alpha_table_transpose_col));
+        let tmp = &self.cosine_table_col * &data.component_mul(&self.alpha_table_transpose_col);
 
         //     对每一行做 idct
-        (tmp.component_mul(&self.alpha_table_row)) * &self.cosine_table_transpose_row
+        &tmp.component_mul(&self.alpha_table_row) * &self.cosine_table_transpose_row
+    }
+
+
+    /// Two-dimensional DCT-II of a complex matrix.
+    ///
+    /// The real and imaginary parts go through each cosine table in a single product.
+    pub fn dct_2d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        // 对每行做 dct：实部、虚部上下堆叠
+        let tmp = merge_rows(&(split_rows(data) * &self.cosine_table_row));
+        // 对每列做 dct：实部、虚部左右拼接
+        let res = merge_cols(&(&self.cosine_table_transpose_col * &split_cols(&tmp)));
+        DMatrix::from_fn(res.nrows(), res.ncols(), |i, j| {
+            res[(i, j)] * (self.alpha_table_transpose_col[(i, 0)] * self.alpha_table_row[(0, j)])
+        })
     }
+
+
+    /// Two-dimensional IDCT-III of a complex matrix
+    pub fn idct_2d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let weighted = DMatrix::from_fn(data.nrows(), data.ncols(), |i, j| {
+            data[(i, j)] * (self.alpha_table_transpose_col[(i, 0)] * self.alpha_table_row[(0, j)])
+        });
+        //     对每一列做 idct
+        let tmp = merge_cols(&(&self.cosine_table_col * &split_cols(&weighted)));
+        //     对每一行做 idct
+        merge_rows(&(split_rows(&tmp) * &self.cosine_table_transpose_row))
+    }
+}
+
+/// Block-wise 2-D DCT-II on `block × block` tiles, as used by JPEG-style codecs.
+///
+/// An image whose sides are not multiples of the block size is first extended on the
+/// bottom and right with an `ExtensionMode` (JPEG replicates the edge, `Constant`), so
+/// the coefficient matrix is `⌈row/B⌉·B × ⌈col/B⌉·B`; `idct_2d` crops back to `row × col`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockDct2D<T: RealField = f32> {
+    dct: Dct2D<T>,
+    block: usize,
+    mode: ExtensionMode,
+    row: usize,
+    col: usize,
 }
 
+impl<T: RealField + Copy> BlockDct2D<T> {
+    pub fn new(row: usize, col: usize, block: usize, mode: ExtensionMode) -> Self {
+        assert!(block > 0, "block size must be positive");
+        Self { dct: Dct2D::new(block, block), block, mode, row, col }
+    }
+
+    pub fn block(&self) -> usize {
+        self.block
+    }
+
+    pub fn mode(&self) -> ExtensionMode {
+        self.mode
+    }
+
+    /// Shape of the coefficient matrix, both sides rounded up to a multiple of the block size
+    pub fn coefficient_shape(&self) -> (usize, usize) {
+        (self.row.next_multiple_of(self.block), self.col.next_multiple_of(self.block))
+    }
+
+    /// DCT-II of every tile of the extended image
+    pub fn dct_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        assert_eq!(data.shape(), (self.row, self.col), "data shape does not match the plan");
+        let (rows, cols) = self.coefficient_shape();
+        let extended = pad2(data, (0, rows - self.row), (0, cols - self.col), self.mode);
+        self.map_blocks(&extended, |tile| self.dct.dct_2d(tile))
+    }
+
+    /// Inverse of `dct_2d`, cropped to the original image size
+    pub fn idct_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        assert_eq!(data.shape(), self.coefficient_shape(), "coefficient shape does not match the plan");
+        self.map_blocks(data, |tile| self.dct.idct_2d(tile)).view((0, 0), (self.row, self.col)).into_owned()
+    }
+
+    fn map_blocks(&self, data: &DMatrix<T>, transform: impl Fn(&DMatrix<T>) -> DMatrix<T>) -> DMatrix<T> {
+        let b = self.block;
+        let mut res = DMatrix::zeros(data.nrows(), data.ncols());
+        for i in (0..data.nrows()).step_by(b) {
+            for j in (0..data.ncols()).step_by(b) {
+                let tile = transform(&data.view((i, j), (b, b)).into_owned());
+                res.view_mut((i, j), (b, b)).copy_from(&tile);
+            }
+        }
+        res
+    }
+}
+
+
 /// `Dct4x4` is 20x faster than `Dct2D`
+///
+/// All tables are stack-allocated, so it works without a heap allocator in `no_std` builds.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dct4x4 {
     cosine_table_row: OMatrix<f32, U4, U4>,
     alpha_table_row: OMatrix<f32, U4, U4>,
@@ -143,11 +295,12 @@ pub struct Dct4x4 {
     alpha_table_transpose_col: OMatrix<f32, U4, U4>,
 }
 
+#[allow(clippy::new_without_default, clippy::clone_on_copy, clippy::op_ref)]
 impl Dct4x4 {
     pub fn new() -> Self {
         let size = 4;
-        let cosine_table_row = Matrix4::from_row_slice(&generate_cosine_table(size));
-        let alpha_table_row = Matrix1x4::from_vec(generate_alpha_table(size));
+        let cosine_table_row = Matrix4::from_fn(|x, u| cosine(x, u, size));
+        let alpha_table_row = Matrix1x4::from_fn(|_, u| alpha(u, size));
 
         // repeat
         let alpha_table_row = Matrix4x1::from_element(1.0) * alpha_table_row;
@@ -1,6 +1,14 @@
 #[cfg(feature = "dct_raw")]
 pub mod dct_raw_algo {
-    use std::f64::consts::PI;
+    //! Naive O(N²) transforms in `f64`, used as ground truth for the fast plans.
+    //!
+    //! Every transform uses the same orthonormal scaling as the plans in
+    //! [`dct`](crate::dct) and [`dst`](crate::dst).
+    use core::f64::consts::PI;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    #[cfg(not(feature = "std"))]
+    use num_traits::Float;
 
     fn alpha(u: usize, n: usize) -> f64 {
         if u == 0 {
@@ -10,77 +18,190 @@ pub mod dct_raw_algo {
         }
     }
 
+    fn beta(u: usize, n: usize) -> f64 {
+        if u == n - 1 {
+            (1.0 / n as f64).sqrt()
+        } else {
+            (2.0 / n as f64).sqrt()
+        }
+    }
+
+    fn cosine(x: usize, u: usize, n: usize) -> f64 {
+        ((2 * x + 1) as f64 * u as f64 * PI / (2.0 * n as f64)).cos()
+    }
+
+    fn sine(x: usize, u: usize, n: usize) -> f64 {
+        ((2 * x + 1) as f64 * (u + 1) as f64 * PI / (2.0 * n as f64)).sin()
+    }
+
+    /// 一维 DCT-II
     pub fn dct_1d(vector: &[f64]) -> Vec<f64> {
         let n = vector.len();
-        let mut result = vec![0.0; n];
-        for u in 0..n {
-            let mut sum = 0.0;
-            for x in 0..n {
-                sum += vector[x] * ((2 * x + 1) as f64 * u as f64 * PI / (2.0 * n as f64)).cos();
-            }
-            result[u] = alpha(u, n) * sum;
-        }
-        result
+        (0..n).map(|u| {
+            let sum: f64 = vector.iter().enumerate().map(|(x, v)| v * cosine(x, u, n)).sum();
+            alpha(u, n) * sum
+        }).collect()
     }
 
+    /// 一维 IDCT（DCT-III）
     pub fn idct_1d(vector: &[f64]) -> Vec<f64> {
         let n = vector.len();
-        let mut result = vec![0.0; n];
-        for x in 0..n {
-            let mut sum = 0.0;
-            for u in 0..n {
-                sum += alpha(u, n) * vector[u] * ((2 * x + 1) as f64 * u as f64 * PI / (2.0 * n as f64)).cos();
-            }
-            result[x] = sum;
-        }
-        result
+        (0..n).map(|x| {
+            vector.iter().enumerate().map(|(u, v)| alpha(u, n) * v * cosine(x, u, n)).sum()
+        }).collect()
     }
 
-    /// 二维 DCT
-    pub fn dct_2d(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
-        let n = matrix.len();
-        let mut intermediate = vec![vec![0.0; n]; n];
-        let mut dct_matrix = vec![vec![0.0; n]; n];
+    /// 一维 DST-II
+    pub fn dst_1d(vector: &[f64]) -> Vec<f64> {
+        let n = vector.len();
+        (0..n).map(|u| {
+            let sum: f64 = vector.iter().enumerate().map(|(x, v)| v * sine(x, u, n)).sum();
+            beta(u, n) * sum
+        }).collect()
+    }
 
-        // 对每一行应用一维 DCT
-        for i in 0..n {
-            intermediate[i] = dct_1d(&matrix[i]);
-        }
+    /// 一维 IDST（DST-III）
+    pub fn idst_1d(vector: &[f64]) -> Vec<f64> {
+        let n = vector.len();
+        (0..n).map(|x| {
+            vector.iter().enumerate().map(|(u, v)| beta(u, n) * v * sine(x, u, n)).sum()
+        }).collect()
+    }
+
+    /// 先对每一行、再对每一列应用一维变换，矩阵可以不是方阵
+    fn separable_2d(matrix: &[Vec<f64>], transform: fn(&[f64]) -> Vec<f64>) -> Vec<Vec<f64>> {
+        let rows = matrix.len();
+        let cols = matrix.first().map_or(0, |row| row.len());
+        assert!(matrix.iter().all(|row| row.len() == cols), "all rows must have the same length");
+
+        // 对每一行应用一维变换
+        let intermediate: Vec<Vec<f64>> = matrix.iter().map(|row| transform(row)).collect();
 
-        // 对每一列应用一维 DCT
-        for j in 0..n {
+        // 对每一列应用一维变换
+        let mut result = vec![vec![0.0; cols]; rows];
+        for j in 0..cols {
             let column: Vec<f64> = intermediate.iter().map(|row| row[j]).collect();
-            let dct_col = dct_1d(&column);
-            for i in 0..n {
-                dct_matrix[i][j] = dct_col[i];
+            for (i, v) in transform(&column).into_iter().enumerate() {
+                result[i][j] = v;
             }
         }
+        result
+    }
 
-        dct_matrix
+    /// 二维 DCT
+    pub fn dct_2d(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        separable_2d(matrix, dct_1d)
     }
 
     /// 二维 IDCT
     pub fn idct_2d(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
-        let n = matrix.len();
-        let mut intermediate = vec![vec![0.0; n]; n];
-        let mut idct_matrix = vec![vec![0.0; n]; n];
-
-        // 对每一列应用一维 IDCT
-        for j in 0..n {
-            let column: Vec<f64> = matrix.iter().map(|row| row[j]).collect();
-            let idct_col = idct_1d(&column);
-            for i in 0..n {
-                intermediate[i][j] = idct_col[i];
+        separable_2d(matrix, idct_1d)
+    }
+
+    /// 二维 DST
+    pub fn dst_2d(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        separable_2d(matrix, dst_1d)
+    }
+
+    /// 二维 IDST
+    pub fn idst_2d(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        separable_2d(matrix, idst_1d)
+    }
+
+
+    /// Transforms covered by the reference implementation
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum TransformKind {
+        /// DCT-II
+        Dct,
+        /// DCT-III, the inverse of DCT-II
+        Idct,
+        /// DST-II
+        Dst,
+        /// DST-III, the inverse of DST-II
+        Idst,
+    }
+
+    impl TransformKind {
+        pub fn reference_1d(self, vector: &[f64]) -> Vec<f64> {
+            match self {
+                TransformKind::Dct => dct_1d(vector),
+                TransformKind::Idct => idct_1d(vector),
+                TransformKind::Dst => dst_1d(vector),
+                TransformKind::Idst => idst_1d(vector),
             }
         }
 
-        // 对每一行应用一维 IDCT
-        for i in 0..n {
-            idct_matrix[i] = idct_1d(&intermediate[i]);
+        pub fn reference_2d(self, matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+            match self {
+                TransformKind::Dct => dct_2d(matrix),
+                TransformKind::Idct => idct_2d(matrix),
+                TransformKind::Dst => dst_2d(matrix),
+                TransformKind::Idst => idst_2d(matrix),
+            }
         }
+    }
+
 
-        idct_matrix
+    /// Error of a fast transform against the reference, for one input shape
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct AccuracyReport {
+        pub rows: usize,
+        pub cols: usize,
+        pub max_error: f64,
+        pub rms_error: f64,
+    }
+
+    /// Deterministic test data in `[-1, 1)`, so reports are reproducible
+    fn test_signal(len: usize, seed: usize) -> Vec<f64> {
+        let mut state = (seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ 0x2545_F491_4F6C_DD1D;
+        (0..len).map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        }).collect()
     }
-}
 
+    fn measure(rows: usize, cols: usize, expected: &[f64], actual: &[f64]) -> AccuracyReport {
+        assert_eq!(expected.len(), actual.len(), "fast transform returned the wrong number of values");
+        let mut max_error: f64 = 0.0;
+        let mut sum_sq = 0.0;
+        for (e, a) in expected.iter().zip(actual) {
+            let err = (e - a).abs();
+            max_error = max_error.max(err);
+            sum_sq += err * err;
+        }
+        let rms_error = if expected.is_empty() { 0.0 } else { (sum_sq / expected.len() as f64).sqrt() };
+        AccuracyReport { rows, cols, max_error, rms_error }
+    }
+
+    /// Measure a fast 1-D transform against the reference for every size in `sizes`.
+    ///
+    /// `fast(size, input)` must return the transform of `input`; build or look up the plan inside it.
+    pub fn accuracy_report<F>(kind: TransformKind, sizes: &[usize], mut fast: F) -> Vec<AccuracyReport>
+    where
+        F: FnMut(usize, &[f64]) -> Vec<f64>,
+    {
+        sizes.iter().map(|&size| {
+            let input = test_signal(size, size);
+            measure(1, size, &kind.reference_1d(&input), &fast(size, &input))
+        }).collect()
+    }
 
+    /// Measure a fast 2-D transform against the reference for every `(rows, cols)` in `shapes`.
+    ///
+    /// `fast(input)` receives the matrix as a list of rows and must return it in the same layout.
+    pub fn accuracy_report_2d<F>(kind: TransformKind, shapes: &[(usize, usize)], mut fast: F) -> Vec<AccuracyReport>
+    where
+        F: FnMut(&[Vec<f64>]) -> Vec<Vec<f64>>,
+    {
+        shapes.iter().map(|&(rows, cols)| {
+            let data = test_signal(rows * cols, rows * 7919 + cols);
+            let input: Vec<Vec<f64>> = data.chunks(cols.max(1)).take(rows).map(|row| row.to_vec()).collect();
+            let expected: Vec<f64> = kind.reference_2d(&input).concat();
+            let actual: Vec<f64> = fast(&input).concat();
+            measure(rows, cols, &expected, &actual)
+        }).collect()
+    }
+}
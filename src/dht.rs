@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+use nalgebra::{convert, DMatrix, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use crate::fft::RealFft;
+
+/// `1/√N`, which makes the transform its own inverse
+fn unitary_scale<T: RealField + Copy>(size: usize) -> T {
+    convert(1.0 / (size.max(1) as f64).sqrt())
+}
+
+/// Unscaled DHT of one line from its real FFT, `H(k) = Re X(k) - Im X(k)`
+fn hartley<T: RealField + Copy>(fft: &RealFft<T>, data: &[T], scale: T) -> Vec<T> {
+    let n = data.len();
+    let spectrum = fft.rfft(data);
+    (0..n)
+        .map(|k| {
+            // k > N/2 时 X(k) = conj(X(N-k))
+            let v = if k < spectrum.len() { spectrum[k] } else { spectrum[n - k].conj() };
+            (v.re - v.im) * scale
+        })
+        .collect()
+}
+
+/// Apply `hartley` to every row of `data`
+fn hartley_rows<T: RealField + Copy>(fft: &RealFft<T>, data: &DMatrix<T>, scale: T) -> DMatrix<T> {
+    assert_eq!(data.ncols(), fft.len(), "row length must equal the plan size");
+    let mut res = DMatrix::zeros(data.nrows(), data.ncols());
+    let mut buf = Vec::with_capacity(data.ncols());
+    for i in 0..data.nrows() {
+        buf.clear();
+        buf.extend(data.row(i).iter().copied());
+        for (j, v) in hartley(fft, &buf, scale).into_iter().enumerate() {
+            res[(i, j)] = v;
+        }
+    }
+    res
+}
+
+/// Apply `hartley` to every column of `data`
+fn hartley_cols<T: RealField + Copy>(fft: &RealFft<T>, data: &DMatrix<T>, scale: T) -> DMatrix<T> {
+    assert_eq!(data.nrows(), fft.len(), "column length must equal the plan size");
+    let mut res = data.clone();
+    for mut column in res.column_iter_mut() {
+        let v = hartley(fft, column.as_slice(), scale);
+        column.as_mut_slice().copy_from_slice(&v);
+    }
+    res
+}
+
+
+/// Discrete Hartley Transform plan.
+///
+/// The DHT is real-to-real and computed from a real FFT in O(N log N).
+/// With the `1/√N` scaling it is its own inverse, so `idht_1d` is the same operation.
+///
+/// Formula:
+/// ```latex
+/// H(k) = \frac{1}{\sqrt{N}} \sum_{n=0}^{N-1} x(n) \operatorname{cas} \left( \frac{2\pi nk}{N} \right),
+/// \quad \operatorname{cas}\theta = \cos\theta + \sin\theta
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dht<T: RealField = f32> {
+    fft: RealFft<T>,
+    scale: T,
+}
+
+impl<T: RealField + Copy> Dht<T> {
+    pub fn new(size: usize) -> Self {
+        Self { fft: RealFft::new(size), scale: unitary_scale(size) }
+    }
+
+    /// One-dimensional DHT of every row of `data`
+    pub fn dht_1d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        hartley_rows(&self.fft, data, self.scale)
+    }
+
+    /// Inverse DHT, identical to `dht_1d`
+    pub fn idht_1d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        self.dht_1d(data)
+    }
+}
+
+
+/// Two-dimensional Discrete Hartley Transform plan for `row × col` matrices.
+///
+/// Two kernels are in use and both are provided, scaled by `1/√(MN)` so that each is
+/// its own inverse:
+/// - `dht_2d_separable`: `cas(2πux/M)·cas(2πvy/N)`, a 1-D DHT along rows then columns
+/// - `dht_2d`: the true 2-D kernel `cas(2π(ux/M + vy/N))`, obtained from the
+///   separable one by `H(u,v) = ½[T(u,v) + T(-u,v) + T(u,-v) - T(-u,-v)]`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dht2D<T: RealField = f32> {
+    fft_row: RealFft<T>,
+    scale_row: T,
+    fft_col: RealFft<T>,
+    scale_col: T,
+}
+
+impl<T: RealField + Copy> Dht2D<T> {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self {
+            fft_row: RealFft::new(col),
+            scale_row: unitary_scale(col),
+            fft_col: RealFft::new(row),
+            scale_col: unitary_scale(row),
+        }
+    }
+
+    /// Separable two-dimensional DHT with the kernel `cas(2πux/M)·cas(2πvy/N)`
+    pub fn dht_2d_separable(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        // 先对每行、再对每列做一维 DHT
+        let tmp = hartley_rows(&self.fft_row, data, self.scale_row);
+        hartley_cols(&self.fft_col, &tmp, self.scale_col)
+    }
+
+    /// Inverse of `dht_2d_separable`, identical to it
+    pub fn idht_2d_separable(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        self.dht_2d_separable(data)
+    }
+
+    /// Two-dimensional DHT with the kernel `cas(2π(ux/M + vy/N))`
+    pub fn dht_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        let t = self.dht_2d_separable(data);
+        let (rows, cols) = t.shape();
+        let half: T = convert(0.5);
+        DMatrix::from_fn(rows, cols, |u, v| {
+            let (nu, nv) = ((rows - u) % rows, (cols - v) % cols);
+            (t[(u, v)] + t[(nu, v)] + t[(u, nv)] - t[(nu, nv)]) * half
+        })
+    }
+
+    /// Inverse of `dht_2d`, identical to it
+    pub fn idht_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        self.dht_2d(data)
+    }
+}
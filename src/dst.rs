@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use nalgebra::{convert, Complex, DMatrix, Dyn, OMatrix, RealField};
+use crate::dct::{merge_cols, merge_rows, split_cols, split_rows};
+
+fn generate_sine_table<T: RealField + Copy>(size: usize) -> Vec<T> {
+    let mut data = Vec::with_capacity(size * size);
+    for x in 0..size {
+        for u in 0..size {
+            data.push((T::pi() * convert(x as f64 + 0.5) * convert((u + 1) as f64) / convert(size as f64)).sin())
+        }
+    }
+    data
+}
+
+fn generate_beta_table<T: RealField + Copy>(size: usize) -> Vec<T> {
+    let mut data = Vec::with_capacity(size);
+    for u in 0..size {
+        if u == size - 1 {
+            data.push((T::one() / convert(size as f64)).sqrt());
+        } else {
+            data.push((convert::<f64, T>(2.0) / convert(size as f64)).sqrt());
+        }
+    }
+    data
+}
+
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Dst<T: RealField = f32> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) sine_table: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) beta_table: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    sine_table_transpose: OMatrix<T, Dyn, Dyn>,
+}
+
+impl<T: RealField + Copy> Dst<T> {
+    pub fn new(size: usize) -> Self {
+        let sine_table = DMatrix::from_row_slice(size, size, &generate_sine_table(size));
+        let beta_table = DMatrix::from_vec(1, size, generate_beta_table(size));
+        Self {
+            sine_table_transpose: sine_table.transpose(),
+            sine_table,
+            beta_table,
+        }
+    }
+
+
+    /// One-dimensional Discrete Sine Transform（DST-II）
+    ///
+    /// Formula:
+    /// ```latex
+    /// F(u) = \beta(u) \sum_{x=0}^{N-1} f(x) \sin \left( \frac{(2x + 1)(u + 1)\pi}{2N} \right)
+    ///
+    /// \beta(u) =
+    /// \begin{cases}
+    ///     \sqrt{\frac{1}{N}} & \text{if } u = N - 1 \
+    ///     \sqrt{\frac{2}{N}} & \text{otherwise}
+    /// \end{cases}
+    /// ```
+    pub fn dst_1d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
+        (data * &self.sine_table).component_mul(&self.beta_table)
+    }
+
+
+    /// One-dimensional Inverse Discrete Sine Transform（IDST-III）
+    ///
+    /// Formula:
+    /// ```latex
+    /// f(x) = \sum_{u=0}^{N-1} \beta(u) F(u) \sin \left( \frac{(2x + 1)(u + 1)\pi}{2N} \right)
+    /// ```
+    pub fn idst_1d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
+        (data.component_mul(&self.beta_table)) * &self.sine_table_transpose
+    }
+
+
+    /// DST-II of every row of a complex matrix.
+    ///
+    /// The real and imaginary parts go through the sine table in a single product.
+    pub fn dst_1d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let mut res = merge_rows(&(split_rows(data) * &self.sine_table));
+        for (j, mut col) in res.column_iter_mut().enumerate() {
+            let b = self.beta_table[(0, j)];
+            col.iter_mut().for_each(|v| *v *= b);
+        }
+        res
+    }
+
+
+    /// IDST-III of every row of a complex matrix
+    pub fn idst_1d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let weighted = DMatrix::from_fn(data.nrows(), data.ncols(), |i, j| data[(i, j)] * self.beta_table[(0, j)]);
+        merge_rows(&(split_rows(&weighted) * &self.sine_table_transpose))
+    }
+}
+
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Dst2D<T: RealField = f32> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) sine_table_row: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) beta_table_row: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    sine_table_transpose_row: OMatrix<T, Dyn, Dyn>,
+
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) sine_table_col: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    sine_table_transpose_col: OMatrix<T, Dyn, Dyn>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub(crate) beta_table_transpose_col: OMatrix<T, Dyn, Dyn>,
+}
+
+impl<T: RealField + Copy> Dst2D<T> {
+    pub fn new(row: usize, col: usize) -> Self {
+        let sine_table_row = DMatrix::from_row_slice(col, col, &generate_sine_table(col));
+        let beta_table_row = DMatrix::from_vec(1, col, generate_beta_table(col));
+        let sine_table_col = DMatrix::from_row_slice(row, row, &generate_sine_table(row));
+        let beta_table_col = DMatrix::from_vec(1, row, generate_beta_table(row));
+
+        // repeat
+        let beta_table_row = DMatrix::from_element(row, 1, T::one()) * beta_table_row;
+        let beta_table_col = DMatrix::from_element(col, 1, T::one()) * beta_table_col;
+
+        Self {
+            sine_table_transpose_row: sine_table_row.transpose(),
+            sine_table_row,
+            beta_table_row,
+
+            sine_table_transpose_col: sine_table_col.transpose(),
+            sine_table_col,
+            beta_table_transpose_col: beta_table_col.transpose(),
+        }
+    }
+
+    /// Two-dimensional Discrete Sine Transform（DST-II）
+    pub fn dst_2d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
+        // 对每行做 dst
+        let tmp = (data * &self.sine_table_row).component_mul(&self.beta_table_row);
+        // 对每列做 dst
+        (&self.sine_table_transpose_col * &tmp).component_mul(&self.beta_table_transpose_col)
+    }
+
+
+    /// Two-dimensional Inverse Discrete Sine Transform（IDST-III）
+    pub fn idst_2d(&self, data: &OMatrix<T, Dyn, Dyn>) -> OMatrix<T, Dyn, Dyn> {
+        //     对每一列做 idst
+        let tmp = &self.sine_table_col * data.component_mul(&self.beta_table_transpose_col);
+
+        //     对每一行做 idst
+        tmp.component_mul(&self.beta_table_row) * &self.sine_table_transpose_row
+    }
+
+
+    /// Two-dimensional DST-II of a complex matrix
+    pub fn dst_2d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let tmp = merge_rows(&(split_rows(data) * &self.sine_table_row));
+        let res = merge_cols(&(&self.sine_table_transpose_col * &split_cols(&tmp)));
+        DMatrix::from_fn(res.nrows(), res.ncols(), |i, j| {
+            res[(i, j)] * (self.beta_table_transpose_col[(i, 0)] * self.beta_table_row[(0, j)])
+        })
+    }
+
+
+    /// Two-dimensional IDST-III of a complex matrix
+    pub fn idst_2d_complex(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let weighted = DMatrix::from_fn(data.nrows(), data.ncols(), |i, j| {
+            data[(i, j)] * (self.beta_table_transpose_col[(i, 0)] * self.beta_table_row[(0, j)])
+        });
+        let tmp = merge_cols(&(&self.sine_table_col * &split_cols(&weighted)));
+        merge_rows(&(split_rows(&tmp) * &self.sine_table_transpose_row))
+    }
+}
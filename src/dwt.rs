@@ -0,0 +1,488 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::{convert, DMatrix, RealField};
+use crate::extension::pad;
+use crate::wavelet::Wavelet;
+
+pub use crate::extension::ExtensionMode;
+
+/// Filter bank of a wavelet converted to the plan's scalar type
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Filters<T> {
+    pub(crate) dec_lo: Vec<T>,
+    pub(crate) dec_hi: Vec<T>,
+    pub(crate) rec_lo: Vec<T>,
+    pub(crate) rec_hi: Vec<T>,
+}
+
+impl<T: RealField + Copy> Filters<T> {
+    pub(crate) fn new(wavelet: &Wavelet) -> Self {
+        let to_t = |f: &[f64]| f.iter().map(|&v| convert(v)).collect();
+        Self {
+            dec_lo: to_t(wavelet.dec_lo()),
+            dec_hi: to_t(wavelet.dec_hi()),
+            rec_lo: to_t(wavelet.rec_lo()),
+            rec_hi: to_t(wavelet.rec_hi()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.dec_lo.len()
+    }
+
+    /// Analysis in one of the redundant modes: the full convolution of the extended
+    /// signal with each filter, keeping the odd samples `1, 3, ..., N + L - 2`
+    fn analysis_extended(&self, x: &[T], mode: ExtensionMode) -> (Vec<T>, Vec<T>) {
+        let len = mode.coefficient_len(x.len(), self.len());
+        // 两端各延拓 L - 1 个样本，x(i) 位于 extended[i + L - 1]
+        let extended = pad(x, self.len() - 1, self.len() - 1, mode);
+        let mut approx = Vec::with_capacity(len);
+        let mut detail = Vec::with_capacity(len);
+        for k in 0..len {
+            let (mut a, mut d) = (T::zero(), T::zero());
+            for (j, (&lo, &hi)) in self.dec_lo.iter().zip(&self.dec_hi).enumerate() {
+                let v = extended[2 * k + self.len() - j];
+                a += lo * v;
+                d += hi * v;
+            }
+            approx.push(a);
+            detail.push(d);
+        }
+        (approx, detail)
+    }
+
+    /// Inverse of `analysis_extended`: the upsampled coefficients filtered by the synthesis
+    /// filters, keeping the samples from `L - 2` on, which gives `2·len - L + 2` samples
+    fn synthesis_extended(&self, approx: &[T], detail: &[T]) -> Vec<T> {
+        let filter_len = self.len() as isize;
+        let n = (2 * approx.len() as isize - filter_len + 2).max(0) as usize;
+        let mut res = vec![T::zero(); n];
+        for (k, (&a, &d)) in approx.iter().zip(detail).enumerate() {
+            for (j, (&lo, &hi)) in self.rec_lo.iter().zip(&self.rec_hi).enumerate() {
+                let m = 2 * k as isize + j as isize - (filter_len - 2);
+                if (0..n as isize).contains(&m) {
+                    res[m as usize] += lo * a + hi * d;
+                }
+            }
+        }
+        res
+    }
+
+    /// Single-level analysis of a signal of any length, returning `(approximation, detail)`
+    pub(crate) fn analysis(&self, x: &[T], mode: ExtensionMode) -> (Vec<T>, Vec<T>) {
+        match mode {
+            ExtensionMode::Periodization => {
+                let mut x = x.to_vec();
+                // 奇数长度先重复最后一个样本
+                if let Some(&last) = x.last().filter(|_| !x.len().is_multiple_of(2)) {
+                    x.push(last);
+                }
+                self.analysis_periodic(&x)
+            }
+            _ => self.analysis_extended(x, mode),
+        }
+    }
+
+    /// Single-level synthesis back to a signal of length `size`
+    pub(crate) fn synthesis(&self, approx: &[T], detail: &[T], mode: ExtensionMode, size: usize) -> Vec<T> {
+        assert_eq!(approx.len(), detail.len(), "approximation and detail must have the same length");
+        let mut res = match mode {
+            ExtensionMode::Periodization => self.synthesis_periodic(approx, detail),
+            _ => self.synthesis_extended(approx, detail),
+        };
+        assert!(res.len() >= size, "too few coefficients for the signal length");
+        res.truncate(size);
+        res
+    }
+
+    /// `analysis` of every column of a matrix, returning the low- and high-pass halves
+    fn analysis_columns(&self, data: &DMatrix<T>, mode: ExtensionMode) -> (DMatrix<T>, DMatrix<T>) {
+        let len = mode.coefficient_len(data.nrows(), self.len());
+        let mut lo = DMatrix::zeros(len, data.ncols());
+        let mut hi = DMatrix::zeros(len, data.ncols());
+        for (j, column) in data.column_iter().enumerate() {
+            let (a, d) = self.analysis(column.as_slice(), mode);
+            lo.column_mut(j).copy_from_slice(&a);
+            hi.column_mut(j).copy_from_slice(&d);
+        }
+        (lo, hi)
+    }
+
+    /// `synthesis` of every column pair, back to `size` rows
+    fn synthesis_columns(&self, lo: &DMatrix<T>, hi: &DMatrix<T>, mode: ExtensionMode, size: usize) -> DMatrix<T> {
+        assert_eq!(lo.shape(), hi.shape(), "subbands must have the same shape");
+        let mut res = DMatrix::zeros(size, lo.ncols());
+        for (j, (a, d)) in lo.column_iter().zip(hi.column_iter()).enumerate() {
+            res.column_mut(j).copy_from_slice(&self.synthesis(a.as_slice(), d.as_slice(), mode, size));
+        }
+        res
+    }
+
+    /// Periodic analysis of an even-length signal:
+    /// `a(k) = Σ_j dec_lo(j) x((2k + L/2 - j) mod N)`, and likewise for the detail
+    fn analysis_periodic(&self, x: &[T]) -> (Vec<T>, Vec<T>) {
+        let n = x.len() as isize;
+        let offset = (self.len() / 2) as isize;
+        let mut approx = Vec::with_capacity(x.len() / 2);
+        let mut detail = Vec::with_capacity(x.len() / 2);
+        for k in 0..x.len() / 2 {
+            let (mut a, mut d) = (T::zero(), T::zero());
+            for (j, (&lo, &hi)) in self.dec_lo.iter().zip(&self.dec_hi).enumerate() {
+                let v = x[(2 * k as isize + offset - j as isize).rem_euclid(n) as usize];
+                a += lo * v;
+                d += hi * v;
+            }
+            approx.push(a);
+            detail.push(d);
+        }
+        (approx, detail)
+    }
+
+    /// Inverse of `analysis_periodic`: upsample, filter with the synthesis filters and wrap
+    fn synthesis_periodic(&self, approx: &[T], detail: &[T]) -> Vec<T> {
+        let n = 2 * approx.len() as isize;
+        let offset = 1 - (self.len() / 2) as isize;
+        let mut res = vec![T::zero(); n as usize];
+        for (k, (&a, &d)) in approx.iter().zip(detail).enumerate() {
+            for (j, (&lo, &hi)) in self.rec_lo.iter().zip(&self.rec_hi).enumerate() {
+                let m = (2 * k as isize + j as isize + offset).rem_euclid(n) as usize;
+                res[m] += lo * a + hi * d;
+            }
+        }
+        res
+    }
+}
+
+
+/// Single-level one-dimensional discrete wavelet transform plan for signals of length `size`.
+///
+/// `new` treats the signal as periodic (`pywt`'s `periodization` mode), so each output has
+/// `⌈N/2⌉` coefficients and the transform is orthogonal for orthogonal wavelets; an odd
+/// `N` is first extended by repeating the last sample. `with_mode` selects another
+/// extension, which gives `⌊(N + L - 1)/2⌋` coefficients. In every mode `idwt` returns
+/// exactly `N` samples and the reconstruction is exact.
+///
+/// Formula:
+/// ```latex
+/// a(k) = \sum_j h(j)\, x(2k + L/2 - j), \quad d(k) = \sum_j g(j)\, x(2k + L/2 - j)
+/// ```
+/// where `h`, `g` are the decomposition low- and high-pass filters of length `L`
+/// (periodization; the other modes use `x(2k + 1 - j)` on the extended signal).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dwt<T: RealField = f32> {
+    filters: Filters<T>,
+    mode: ExtensionMode,
+    size: usize,
+}
+
+impl<T: RealField + Copy> Dwt<T> {
+    pub fn new(size: usize, wavelet: &Wavelet) -> Self {
+        Self::with_mode(size, wavelet, ExtensionMode::Periodization)
+    }
+
+    pub fn with_mode(size: usize, wavelet: &Wavelet, mode: ExtensionMode) -> Self {
+        Self { filters: Filters::new(wavelet), mode, size }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn mode(&self) -> ExtensionMode {
+        self.mode
+    }
+
+    /// Length of the approximation and of the detail coefficients
+    pub fn coefficient_len(&self) -> usize {
+        self.mode.coefficient_len(self.size, self.filters.len())
+    }
+
+
+    /// Forward transform, returning `(approximation, detail)`
+    pub fn dwt(&self, data: &[T]) -> (Vec<T>, Vec<T>) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        self.filters.analysis(data, self.mode)
+    }
+
+    /// Inverse transform, reconstructing the signal from `(approximation, detail)`
+    pub fn idwt(&self, approx: &[T], detail: &[T]) -> Vec<T> {
+        let len = self.coefficient_len();
+        assert!(approx.len() == len && detail.len() == len, "coefficient length must equal `coefficient_len()`");
+        self.filters.synthesis(approx, detail, self.mode, self.size)
+    }
+}
+
+
+/// Deepest useful decomposition level, `⌊log₂(N/(L - 1))⌋`, as in `pywt.dwt_max_level`:
+/// beyond it every coefficient is affected by the boundary
+pub fn dwt_max_level(data_len: usize, wavelet: &Wavelet) -> usize {
+    let filter_len = wavelet.filter_len();
+    if filter_len < 2 || data_len < filter_len - 1 {
+        return 0;
+    }
+    (data_len / (filter_len - 1)).ilog2() as usize
+}
+
+
+/// Multi-level wavelet coefficients `[a_n, d_n, d_{n-1}, ..., d_1]`, as returned by `wavedec`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaveletCoefficients<T> {
+    /// Approximation at the coarsest level
+    pub approx: Vec<T>,
+    /// Details, from the coarsest level to the finest
+    pub details: Vec<Vec<T>>,
+    /// 原始信号长度，用于重构时确定每一层的长度
+    signal_len: usize,
+}
+
+impl<T: RealField + Copy> WaveletCoefficients<T> {
+    pub fn level(&self) -> usize {
+        self.details.len()
+    }
+
+    /// Length of the decomposed signal
+    pub fn signal_len(&self) -> usize {
+        self.signal_len
+    }
+
+    /// Lengths of the bands in the order `[a_n, d_n, ..., d_1]`
+    pub fn lengths(&self) -> Vec<usize> {
+        core::iter::once(self.approx.len()).chain(self.details.iter().map(|d| d.len())).collect()
+    }
+
+    /// All coefficients concatenated in the order `[a_n, d_n, ..., d_1]`, e.g. for thresholding
+    pub fn flatten(&self) -> Vec<T> {
+        let mut res = self.approx.clone();
+        self.details.iter().for_each(|d| res.extend_from_slice(d));
+        res
+    }
+
+    /// Coefficients with the same layout as `self`, taken from a vector laid out as by `flatten`
+    pub fn unflatten(&self, flat: &[T]) -> Self {
+        assert_eq!(flat.len(), self.lengths().iter().sum::<usize>(), "flat length must equal the number of coefficients");
+        let (approx, mut rest) = flat.split_at(self.approx.len());
+        let details = self
+            .details
+            .iter()
+            .map(|d| {
+                let (band, tail) = rest.split_at(d.len());
+                rest = tail;
+                band.to_vec()
+            })
+            .collect();
+        Self { approx: approx.to_vec(), details, signal_len: self.signal_len }
+    }
+}
+
+
+/// Multi-level 1-D wavelet decomposition: `level` single-level transforms, each applied
+/// to the previous approximation
+pub fn wavedec<T: RealField + Copy>(
+    signal: &[T],
+    wavelet: &Wavelet,
+    level: usize,
+    mode: ExtensionMode,
+) -> WaveletCoefficients<T> {
+    assert!(!signal.is_empty(), "signal must not be empty");
+    let filters = Filters::new(wavelet);
+    let mut approx = signal.to_vec();
+    let mut details = Vec::with_capacity(level);
+    for _ in 0..level {
+        let (a, d) = filters.analysis(&approx, mode);
+        approx = a;
+        details.push(d);
+    }
+    details.reverse();
+    WaveletCoefficients { approx, details, signal_len: signal.len() }
+}
+
+/// Inverse of `wavedec`, with the same wavelet and mode
+pub fn waverec<T: RealField + Copy>(coeffs: &WaveletCoefficients<T>, wavelet: &Wavelet, mode: ExtensionMode) -> Vec<T> {
+    let filters = Filters::new(wavelet);
+    // 从原始长度推出每一层近似系数的长度
+    let mut sizes = Vec::with_capacity(coeffs.level());
+    let mut n = coeffs.signal_len;
+    for _ in 0..coeffs.level() {
+        sizes.push(n);
+        n = mode.coefficient_len(n, filters.len());
+    }
+    let mut approx = coeffs.approx.clone();
+    for (detail, &size) in coeffs.details.iter().zip(sizes.iter().rev()) {
+        approx = filters.synthesis(&approx, detail, mode, size);
+    }
+    approx
+}
+
+
+/// One level of a 2-D wavelet decomposition.
+///
+/// The first letter is the filter applied along each row (horizontally), the second the
+/// filter along each column (vertically). So `lh` holds the horizontal edges, `hl` the
+/// vertical ones and `hh` the diagonal ones; in `pywt` terms they are `cH`, `cV` and `cD`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Subbands2D<T: RealField> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub ll: DMatrix<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub lh: DMatrix<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub hl: DMatrix<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub hh: DMatrix<T>,
+}
+
+
+/// Single-level two-dimensional discrete wavelet transform plan for `row × col` matrices.
+///
+/// The 1-D transform of `Dwt` is applied to every row and then to every column, with the
+/// same wavelet and extension mode. Odd sizes are handled per axis as in `Dwt`, so
+/// `idwt2` always returns a `row × col` matrix.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dwt2D<T: RealField = f32> {
+    filters: Filters<T>,
+    mode: ExtensionMode,
+    row: usize,
+    col: usize,
+}
+
+impl<T: RealField + Copy> Dwt2D<T> {
+    pub fn new(row: usize, col: usize, wavelet: &Wavelet) -> Self {
+        Self::with_mode(row, col, wavelet, ExtensionMode::Periodization)
+    }
+
+    pub fn with_mode(row: usize, col: usize, wavelet: &Wavelet, mode: ExtensionMode) -> Self {
+        Self { filters: Filters::new(wavelet), mode, row, col }
+    }
+
+    pub fn mode(&self) -> ExtensionMode {
+        self.mode
+    }
+
+    /// Shape of each subband
+    pub fn subband_shape(&self) -> (usize, usize) {
+        (
+            self.mode.coefficient_len(self.row, self.filters.len()),
+            self.mode.coefficient_len(self.col, self.filters.len()),
+        )
+    }
+
+
+    pub fn dwt2(&self, data: &DMatrix<T>) -> Subbands2D<T> {
+        assert_eq!(data.shape(), (self.row, self.col), "input shape must equal the plan shape");
+        dwt2_level(&self.filters, data, self.mode)
+    }
+
+    pub fn idwt2(&self, subbands: &Subbands2D<T>) -> DMatrix<T> {
+        let shape = self.subband_shape();
+        for band in [&subbands.ll, &subbands.lh, &subbands.hl, &subbands.hh] {
+            assert_eq!(band.shape(), shape, "subband shape must equal `subband_shape()`");
+        }
+        idwt2_level(&self.filters, subbands, self.mode, (self.row, self.col))
+    }
+}
+
+fn dwt2_level<T: RealField + Copy>(filters: &Filters<T>, data: &DMatrix<T>, mode: ExtensionMode) -> Subbands2D<T> {
+    // 行方向的滤波通过转置后按列处理
+    let (lo, hi) = filters.analysis_columns(&data.transpose(), mode);
+    let (ll, lh) = filters.analysis_columns(&lo.transpose(), mode);
+    let (hl, hh) = filters.analysis_columns(&hi.transpose(), mode);
+    Subbands2D { ll, lh, hl, hh }
+}
+
+fn idwt2_level<T: RealField + Copy>(
+    filters: &Filters<T>,
+    subbands: &Subbands2D<T>,
+    mode: ExtensionMode,
+    (row, col): (usize, usize),
+) -> DMatrix<T> {
+    let lo = filters.synthesis_columns(&subbands.ll, &subbands.lh, mode, row);
+    let hi = filters.synthesis_columns(&subbands.hl, &subbands.hh, mode, row);
+    filters.synthesis_columns(&lo.transpose(), &hi.transpose(), mode, col).transpose()
+}
+
+
+/// Detail subbands of one level of a 2-D decomposition, named as in `Subbands2D`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Details2D<T: RealField> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub lh: DMatrix<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub hl: DMatrix<T>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub hh: DMatrix<T>,
+}
+
+/// Multi-level 2-D wavelet coefficients, as returned by `wavedec2`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaveletCoefficients2D<T: RealField> {
+    /// Approximation (`LL`) at the coarsest level
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    pub approx: DMatrix<T>,
+    /// Details, from the coarsest level to the finest
+    pub details: Vec<Details2D<T>>,
+    /// 原始图像尺寸
+    shape: (usize, usize),
+}
+
+impl<T: RealField + Copy> WaveletCoefficients2D<T> {
+    pub fn level(&self) -> usize {
+        self.details.len()
+    }
+
+    /// Shape of the decomposed matrix
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+}
+
+
+/// Multi-level 2-D wavelet decomposition, repeatedly splitting the `LL` subband
+pub fn wavedec2<T: RealField + Copy>(
+    data: &DMatrix<T>,
+    wavelet: &Wavelet,
+    level: usize,
+    mode: ExtensionMode,
+) -> WaveletCoefficients2D<T> {
+    assert!(!data.is_empty(), "data must not be empty");
+    let filters = Filters::new(wavelet);
+    let mut approx = data.clone();
+    let mut details = Vec::with_capacity(level);
+    for _ in 0..level {
+        let Subbands2D { ll, lh, hl, hh } = dwt2_level(&filters, &approx, mode);
+        approx = ll;
+        details.push(Details2D { lh, hl, hh });
+    }
+    details.reverse();
+    WaveletCoefficients2D { approx, details, shape: data.shape() }
+}
+
+/// Inverse of `wavedec2`, with the same wavelet and mode
+pub fn waverec2<T: RealField + Copy>(coeffs: &WaveletCoefficients2D<T>, wavelet: &Wavelet, mode: ExtensionMode) -> DMatrix<T> {
+    let filters = Filters::new(wavelet);
+    let mut shapes = Vec::with_capacity(coeffs.level());
+    let (mut row, mut col) = coeffs.shape;
+    for _ in 0..coeffs.level() {
+        shapes.push((row, col));
+        row = mode.coefficient_len(row, filters.len());
+        col = mode.coefficient_len(col, filters.len());
+    }
+    let mut approx = coeffs.approx.clone();
+    for (details, &shape) in coeffs.details.iter().zip(shapes.iter().rev()) {
+        let subbands = Subbands2D { ll: approx, lh: details.lh.clone(), hl: details.hl.clone(), hh: details.hh.clone() };
+        approx = idwt2_level(&filters, &subbands, mode, shape);
+    }
+    approx
+}
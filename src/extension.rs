@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+use nalgebra::{convert, DMatrix, RealField};
+
+/// How a signal is extended past its ends, with the names and coefficient lengths of `pywt`.
+///
+/// Shared by the wavelet transforms and the block-based DCT tiling (`BlockDct2D`), so that a
+/// mode means the same samples everywhere. Extensions longer than the signal keep
+/// repeating the pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtensionMode {
+    /// `... 0 0 | x1 x2 ... xn | 0 0 ...`
+    Zero,
+    /// Edge values repeated, `... x1 x1 | x1 x2 ... xn | xn xn ...`
+    Constant,
+    /// Half-sample symmetric, `... x2 x1 | x1 x2 ... xn | xn xn-1 ...`
+    Symmetric,
+    /// Whole-sample symmetric, `... x3 x2 | x1 x2 ... xn | xn-1 xn-2 ...`
+    Reflect,
+    /// `... xn-1 xn | x1 x2 ... xn | x1 x2 ...`
+    Periodic,
+    /// Periodic, but with only `⌈N/2⌉` coefficients per band, so no redundancy is added;
+    /// as a padding it is the same as `Periodic`
+    Periodization,
+    /// First-order extrapolation from the two samples at each end,
+    /// `... x1 + 2(x1 - x2)  x1 + (x1 - x2) | x1 x2 ... xn | xn + (xn - xn-1) ...`
+    Smooth,
+    /// Half-sample antisymmetric, `... -x2 -x1 | x1 x2 ... xn | -xn -xn-1 ...`
+    Antisymmetric,
+}
+
+impl ExtensionMode {
+    /// Length of each wavelet coefficient band for a signal of length `n` and a filter of
+    /// length `filter_len`: `⌈n/2⌉` for `Periodization`, `⌊(n + filter_len - 1)/2⌋` otherwise
+    pub fn coefficient_len(self, n: usize, filter_len: usize) -> usize {
+        match self {
+            ExtensionMode::Periodization => n.div_ceil(2),
+            _ => (n + filter_len - 1) / 2,
+        }
+    }
+
+    /// Sample `i` of the extended signal, for any integer `i`
+    pub fn sample<T: RealField + Copy>(self, x: &[T], i: isize) -> T {
+        let n = x.len() as isize;
+        if (0..n).contains(&i) {
+            return x[i as usize];
+        }
+        if n == 0 {
+            return T::zero();
+        }
+        match self {
+            ExtensionMode::Zero => T::zero(),
+            ExtensionMode::Constant => x[i.clamp(0, n - 1) as usize],
+            ExtensionMode::Symmetric => {
+                // 周期为 2N 的镜像
+                let r = i.rem_euclid(2 * n);
+                x[if r < n { r } else { 2 * n - 1 - r } as usize]
+            }
+            ExtensionMode::Reflect => {
+                if n == 1 {
+                    return x[0];
+                }
+                // 不重复端点，周期为 2N - 2
+                let r = i.rem_euclid(2 * n - 2);
+                x[if r < n { r } else { 2 * n - 2 - r } as usize]
+            }
+            ExtensionMode::Periodic | ExtensionMode::Periodization => x[i.rem_euclid(n) as usize],
+            ExtensionMode::Smooth => {
+                if n == 1 {
+                    return x[0];
+                }
+                let (edge, inner, distance) = if i < 0 {
+                    (x[0], x[1], -i)
+                } else {
+                    (x[n as usize - 1], x[n as usize - 2], i - n + 1)
+                };
+                edge + (edge - inner) * convert(distance as f64)
+            }
+            ExtensionMode::Antisymmetric => {
+                // 镜像两次符号复原，周期仍为 2N
+                let r = i.rem_euclid(2 * n);
+                if r < n { x[r as usize] } else { -x[(2 * n - 1 - r) as usize] }
+            }
+        }
+    }
+}
+
+/// `x` extended by `before` samples on the left and `after` on the right
+pub fn pad<T: RealField + Copy>(x: &[T], before: usize, after: usize, mode: ExtensionMode) -> Vec<T> {
+    (-(before as isize)..(x.len() + after) as isize).map(|i| mode.sample(x, i)).collect()
+}
+
+/// `data` extended by `rows = (top, bottom)` and `cols = (left, right)`, column-wise then row-wise
+pub fn pad2<T: RealField + Copy>(
+    data: &DMatrix<T>,
+    rows: (usize, usize),
+    cols: (usize, usize),
+    mode: ExtensionMode,
+) -> DMatrix<T> {
+    let nrows = data.nrows() + rows.0 + rows.1;
+    let mut tall = DMatrix::zeros(nrows, data.ncols());
+    for (j, column) in data.column_iter().enumerate() {
+        let column: Vec<T> = column.iter().copied().collect();
+        tall.column_mut(j).copy_from_slice(&pad(&column, rows.0, rows.1, mode));
+    }
+    let mut res = DMatrix::zeros(nrows, data.ncols() + cols.0 + cols.1);
+    for (i, row) in tall.row_iter().enumerate() {
+        let row: Vec<T> = row.iter().copied().collect();
+        for (j, v) in pad(&row, cols.0, cols.1, mode).into_iter().enumerate() {
+            res[(i, j)] = v;
+        }
+    }
+    res
+}
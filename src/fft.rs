@@ -0,0 +1,524 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, Complex, DMatrix, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Prime factors larger than this are handled by Bluestein's algorithm
+const MAX_GENERIC_RADIX: usize = 13;
+
+/// `e^{i·angle}`, evaluated in `f64` before rounding to `T`
+pub(crate) fn expi<T: RealField + Copy>(angle: f64) -> Complex<T> {
+    Complex::new(convert(angle.cos()), convert(angle.sin()))
+}
+
+/// Split `n` into radices, 4 first, then 2, 3, 5, ...
+///
+/// Each entry is `(p, m)`: a radix-`p` butterfly over `p` sub-transforms of length `m`.
+fn factorize(mut n: usize) -> Vec<(usize, usize)> {
+    let mut factors = Vec::new();
+    let mut p = 4;
+    let floor_sqrt = (n as f64).sqrt().floor() as usize;
+    while n > 1 {
+        while !n.is_multiple_of(p) {
+            p = match p {
+                4 => 2,
+                2 => 3,
+                _ => p + 2,
+            };
+            if p > floor_sqrt {
+                p = n;
+            }
+        }
+        n /= p;
+        factors.push((p, n));
+    }
+    factors
+}
+
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Bluestein<T: RealField> {
+    /// `e^{-iπk²/N}`
+    chirp: Vec<Complex<T>>,
+    /// FFT of the zero-padded conjugate chirp
+    kernel: Vec<Complex<T>>,
+    inner: Fft<T>,
+}
+
+
+/// Complex FFT plan of a fixed length.
+///
+/// Lengths made of small primes run a mixed-radix (radix-4, 2, then generic) Cooley-Tukey;
+/// lengths with a prime factor above 13 fall back to Bluestein's algorithm,
+/// so every length is `O(N log N)`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fft<T: RealField = f32> {
+    size: usize,
+    /// `e^{-2πik/N}` for `k` in `0..N`
+    twiddles: Vec<Complex<T>>,
+    factors: Vec<(usize, usize)>,
+    bluestein: Option<Box<Bluestein<T>>>,
+}
+
+impl<T: RealField + Copy> Fft<T> {
+    pub fn new(size: usize) -> Self {
+        let factors = factorize(size);
+        if factors.iter().any(|&(p, _)| p > MAX_GENERIC_RADIX) {
+            return Self::new_bluestein(size);
+        }
+        Self {
+            size,
+            twiddles: (0..size).map(|k| expi(-2.0 * PI * k as f64 / size as f64)).collect(),
+            factors,
+            bluestein: None,
+        }
+    }
+
+    fn new_bluestein(size: usize) -> Self {
+        let m = (2 * size - 1).next_power_of_two();
+        let inner = Fft::new(m);
+
+        // k² 对 2N 取模，避免大 k 时角度失去精度
+        let chirp: Vec<Complex<T>> = (0..size)
+            .map(|k| {
+                let k2 = (k as u128 * k as u128 % (2 * size) as u128) as f64;
+                expi(-PI * k2 / size as f64)
+            })
+            .collect();
+
+        let mut kernel = vec![Complex::new(T::zero(), T::zero()); m];
+        for k in 0..size {
+            kernel[k] = chirp[k].conj();
+            if k > 0 {
+                kernel[m - k] = chirp[k].conj();
+            }
+        }
+        inner.fft_inplace(&mut kernel);
+
+        Self {
+            size,
+            twiddles: Vec::new(),
+            factors: Vec::new(),
+            bluestein: Some(Box::new(Bluestein { chirp, kernel, inner })),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+
+    /// Forward transform
+    ///
+    /// Formula:
+    /// ```latex
+    /// X(k) = \sum_{n=0}^{N-1} x(n) e^{-2\pi i k n / N}
+    /// ```
+    pub fn fft(&self, data: &[Complex<T>]) -> Vec<Complex<T>> {
+        let mut res = data.to_vec();
+        self.fft_inplace(&mut res);
+        res
+    }
+
+
+    /// Inverse transform, scaled by `1/N` so that `ifft(fft(x)) == x`
+    ///
+    /// Formula:
+    /// ```latex
+    /// x(n) = \frac{1}{N} \sum_{k=0}^{N-1} X(k) e^{2\pi i k n / N}
+    /// ```
+    pub fn ifft(&self, data: &[Complex<T>]) -> Vec<Complex<T>> {
+        let mut res = data.to_vec();
+        self.ifft_inplace(&mut res);
+        res
+    }
+
+
+    /// Forward transform in place
+    pub fn fft_inplace(&self, data: &mut [Complex<T>]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        if self.size <= 1 {
+            return;
+        }
+        match &self.bluestein {
+            Some(bluestein) => self.bluestein(bluestein, data),
+            None => {
+                let input = data.to_vec();
+                self.work(data, &input, 0, 1, &self.factors);
+            }
+        }
+    }
+
+
+    /// Inverse transform in place, scaled by `1/N`
+    pub fn ifft_inplace(&self, data: &mut [Complex<T>]) {
+        // ifft(x) = conj(fft(conj(x))) / N
+        data.iter_mut().for_each(|v| *v = v.conj());
+        self.fft_inplace(data);
+        let scale = T::one() / convert(self.size as f64);
+        data.iter_mut().for_each(|v| *v = v.conj() * scale);
+    }
+
+
+    fn bluestein(&self, plan: &Bluestein<T>, data: &mut [Complex<T>]) {
+        let m = plan.kernel.len();
+        let mut buf = vec![Complex::new(T::zero(), T::zero()); m];
+        for (k, v) in data.iter().enumerate() {
+            buf[k] = *v * plan.chirp[k];
+        }
+        // 用长度为 2 的幂的 FFT 计算卷积
+        plan.inner.fft_inplace(&mut buf);
+        buf.iter_mut().zip(plan.kernel.iter()).for_each(|(a, b)| *a *= *b);
+        plan.inner.ifft_inplace(&mut buf);
+        for (k, v) in data.iter_mut().enumerate() {
+            *v = buf[k] * plan.chirp[k];
+        }
+    }
+
+
+    /// Decimation in time: `out` receives the transform of `input[offset + fstride * j]`
+    fn work(&self, out: &mut [Complex<T>], input: &[Complex<T>], offset: usize, fstride: usize, factors: &[(usize, usize)]) {
+        let (p, m) = factors[0];
+        if m == 1 {
+            for (j, v) in out.iter_mut().enumerate().take(p) {
+                *v = input[offset + j * fstride];
+            }
+        } else {
+            for j in 0..p {
+                self.work(&mut out[j * m..(j + 1) * m], input, offset + j * fstride, fstride * p, &factors[1..]);
+            }
+        }
+
+        match p {
+            2 => self.butterfly_2(out, fstride, m),
+            4 => self.butterfly_4(out, fstride, m),
+            _ => self.butterfly_generic(out, fstride, m, p),
+        }
+    }
+
+    fn butterfly_2(&self, out: &mut [Complex<T>], fstride: usize, m: usize) {
+        for k in 0..m {
+            let t = out[k + m] * self.twiddles[k * fstride];
+            out[k + m] = out[k] - t;
+            out[k] += t;
+        }
+    }
+
+    fn butterfly_4(&self, out: &mut [Complex<T>], fstride: usize, m: usize) {
+        for k in 0..m {
+            let s0 = out[k + m] * self.twiddles[k * fstride];
+            let s1 = out[k + 2 * m] * self.twiddles[2 * k * fstride];
+            let s2 = out[k + 3 * m] * self.twiddles[3 * k * fstride];
+
+            let s5 = out[k] - s1;
+            let s4 = out[k] + s1;
+            let s3 = s0 + s2;
+            let s6 = s0 - s2;
+
+            out[k] = s4 + s3;
+            out[k + 2 * m] = s4 - s3;
+            // s6 * (-i)
+            out[k + m] = Complex::new(s5.re + s6.im, s5.im - s6.re);
+            out[k + 3 * m] = Complex::new(s5.re - s6.im, s5.im + s6.re);
+        }
+    }
+
+    fn butterfly_generic(&self, out: &mut [Complex<T>], fstride: usize, m: usize, p: usize) {
+        let mut scratch = vec![Complex::new(T::zero(), T::zero()); p];
+        for u in 0..m {
+            for (q, s) in scratch.iter_mut().enumerate() {
+                *s = out[u + q * m];
+            }
+            for q1 in 0..p {
+                let k = u + q1 * m;
+                let mut twidx = 0;
+                let mut acc = scratch[0];
+                for s in scratch.iter().skip(1) {
+                    twidx += fstride * k;
+                    if twidx >= self.size {
+                        twidx -= self.size;
+                    }
+                    acc += *s * self.twiddles[twidx];
+                }
+                out[k] = acc;
+            }
+        }
+    }
+}
+
+
+/// Sample frequencies of an `n`-point FFT, with sample spacing `d`
+///
+/// `[0, 1, ..., ⌈n/2⌉-1, -⌊n/2⌋, ..., -1] / (d·n)`, the same order as the output of `Fft::fft`.
+pub fn fftfreq<T: RealField + Copy>(n: usize, d: T) -> Vec<T> {
+    let scale = T::one() / (d * convert(n as f64));
+    let positive = n.div_ceil(2);
+    (0..n)
+        .map(|k| {
+            let k = if k < positive { k as f64 } else { k as f64 - n as f64 };
+            convert::<f64, T>(k) * scale
+        })
+        .collect()
+}
+
+
+/// Move the zero-frequency bin to the center of the spectrum
+pub fn fftshift<U: Clone>(data: &[U]) -> Vec<U> {
+    let n = data.len();
+    let shift = n / 2;
+    (0..n).map(|i| data[(i + n - shift) % n].clone()).collect()
+}
+
+
+/// Inverse of `fftshift`
+pub fn ifftshift<U: Clone>(data: &[U]) -> Vec<U> {
+    let n = data.len();
+    let shift = n / 2;
+    (0..n).map(|i| data[(i + shift) % n].clone()).collect()
+}
+
+
+/// Sample frequencies of an `n`-point real FFT, i.e. the `n/2 + 1` bins returned by `RealFft::rfft`
+pub fn rfftfreq<T: RealField + Copy>(n: usize, d: T) -> Vec<T> {
+    let scale = T::one() / (d * convert(n as f64));
+    (0..n / 2 + 1).map(|k| convert::<f64, T>(k as f64) * scale).collect()
+}
+
+
+/// Real-input FFT plan, returning only the `N/2 + 1` non-redundant bins.
+///
+/// For even `N` the signal is packed into a complex signal of length `N/2`,
+/// so the work and memory are about half of a complex FFT.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RealFft<T: RealField = f32> {
+    size: usize,
+    /// 偶数长度时是 N/2 点的复数 FFT，奇数长度时是 N 点
+    inner: Fft<T>,
+    /// `e^{-2πik/N}` for `k` in `0..N/2`
+    twiddles: Vec<Complex<T>>,
+}
+
+impl<T: RealField + Copy> RealFft<T> {
+    pub fn new(size: usize) -> Self {
+        if size.is_multiple_of(2) {
+            Self {
+                size,
+                inner: Fft::new(size / 2),
+                twiddles: (0..size / 2).map(|k| expi(-2.0 * PI * k as f64 / size as f64)).collect(),
+            }
+        } else {
+            Self { size, inner: Fft::new(size), twiddles: Vec::new() }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+
+    /// Forward transform of a real signal, returning bins `0..=N/2`
+    pub fn rfft(&self, data: &[T]) -> Vec<Complex<T>> {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        let n = self.size;
+        if n == 0 {
+            return Vec::new();
+        }
+        if !n.is_multiple_of(2) {
+            let mut buf: Vec<Complex<T>> = data.iter().map(|&v| Complex::new(v, T::zero())).collect();
+            self.inner.fft_inplace(&mut buf);
+            buf.truncate(n / 2 + 1);
+            return buf;
+        }
+
+        // 偶数点放实部，奇数点放虚部
+        let half = n / 2;
+        let mut z: Vec<Complex<T>> = data.chunks(2).map(|pair| Complex::new(pair[0], pair[1])).collect();
+        self.inner.fft_inplace(&mut z);
+
+        let one_half: T = convert(0.5);
+        let mut res = Vec::with_capacity(half + 1);
+        for k in 0..=half {
+            let a = z[k % half];
+            let b = z[(half - k) % half].conj();
+            let even = (a + b) * one_half;
+            // (a - b) / 2i
+            let odd = Complex::new(a.im - b.im, b.re - a.re) * one_half;
+            let w = if k < half { self.twiddles[k] } else { Complex::new(-T::one(), T::zero()) };
+            res.push(even + w * odd);
+        }
+        res
+    }
+
+
+    /// Inverse of `rfft`, scaled by `1/N`.
+    ///
+    /// The imaginary parts of bin 0 (and bin `N/2` for even `N`) are ignored.
+    pub fn irfft(&self, data: &[Complex<T>]) -> Vec<T> {
+        let n = self.size;
+        if n == 0 {
+            return Vec::new();
+        }
+        assert_eq!(data.len(), n / 2 + 1, "input must hold N/2 + 1 bins");
+        if !n.is_multiple_of(2) {
+            // 按共轭对称补全整个频谱
+            let mut buf: Vec<Complex<T>> = (0..n)
+                .map(|k| if k < data.len() { data[k] } else { data[n - k].conj() })
+                .collect();
+            buf[0].im = T::zero();
+            self.inner.ifft_inplace(&mut buf);
+            return buf.iter().map(|v| v.re).collect();
+        }
+
+        let half = n / 2;
+        let one_half: T = convert(0.5);
+        let mut z: Vec<Complex<T>> = (0..half)
+            .map(|k| {
+                let a = if k == 0 { Complex::new(data[0].re, T::zero()) } else { data[k] };
+                let b = if k == 0 { Complex::new(data[half].re, T::zero()) } else { data[half - k].conj() };
+                let even = (a + b) * one_half;
+                let odd = (a - b) * self.twiddles[k].conj() * one_half;
+                // even + i·odd
+                Complex::new(even.re - odd.im, even.im + odd.re)
+            })
+            .collect();
+        self.inner.ifft_inplace(&mut z);
+        z.iter().flat_map(|v| [v.re, v.im]).collect()
+    }
+}
+
+
+/// Two-dimensional real-input FFT plan for `row × col` matrices.
+///
+/// Each row is transformed with `RealFft`, then each column of the `row × (col/2 + 1)`
+/// half spectrum with a complex `Fft`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RealFft2D<T: RealField = f32> {
+    fft_row: RealFft<T>,
+    fft_col: Fft<T>,
+}
+
+impl<T: RealField + Copy> RealFft2D<T> {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { fft_row: RealFft::new(col), fft_col: Fft::new(row) }
+    }
+
+
+    /// Forward transform, returning a `row × (col/2 + 1)` matrix
+    pub fn rfft_2d(&self, data: &DMatrix<T>) -> DMatrix<Complex<T>> {
+        let (rows, cols) = data.shape();
+        assert_eq!((rows, cols), (self.fft_col.len(), self.fft_row.len()), "input shape must equal the plan shape");
+        let bins = cols / 2 + 1;
+        let mut res = DMatrix::from_element(rows, bins, Complex::new(T::zero(), T::zero()));
+
+        // 对每行做 rfft
+        let mut row_buf = Vec::with_capacity(cols);
+        for i in 0..rows {
+            row_buf.clear();
+            row_buf.extend(data.row(i).iter().copied());
+            for (j, v) in self.fft_row.rfft(&row_buf).into_iter().enumerate() {
+                res[(i, j)] = v;
+            }
+        }
+        // 对每列做 fft，列在 DMatrix 中是连续存储的
+        for mut column in res.column_iter_mut() {
+            self.fft_col.fft_inplace(column.as_mut_slice());
+        }
+        res
+    }
+
+
+    /// Inverse of `rfft_2d`, scaled by `1/(row·col)`
+    pub fn irfft_2d(&self, data: &DMatrix<Complex<T>>) -> DMatrix<T> {
+        let (rows, cols) = (self.fft_col.len(), self.fft_row.len());
+        assert_eq!(data.shape(), (rows, cols / 2 + 1), "input must be row × (col/2 + 1)");
+
+        let mut tmp = data.clone();
+        for mut column in tmp.column_iter_mut() {
+            self.fft_col.ifft_inplace(column.as_mut_slice());
+        }
+        let mut res = DMatrix::zeros(rows, cols);
+        let mut row_buf = Vec::with_capacity(tmp.ncols());
+        for i in 0..rows {
+            row_buf.clear();
+            row_buf.extend(tmp.row(i).iter().copied());
+            for (j, v) in self.fft_row.irfft(&row_buf).into_iter().enumerate() {
+                res[(i, j)] = v;
+            }
+        }
+        res
+    }
+}
+
+
+/// Two-dimensional complex FFT plan for `row × col` matrices
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fft2D<T: RealField = f32> {
+    fft_row: Fft<T>,
+    fft_col: Fft<T>,
+}
+
+impl<T: RealField + Copy> Fft2D<T> {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { fft_row: Fft::new(col), fft_col: Fft::new(row) }
+    }
+
+
+    /// Two-dimensional forward transform
+    ///
+    /// Formula:
+    /// ```latex
+    /// X(u, v) = \sum_{x=0}^{M-1} \sum_{y=0}^{N-1} f(x, y) e^{-2\pi i (ux / M + vy / N)}
+    /// ```
+    pub fn fft_2d(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let mut res = data.clone();
+        self.process(&mut res, false);
+        res
+    }
+
+
+    /// Two-dimensional inverse transform, scaled by `1/(row·col)`
+    pub fn ifft_2d(&self, data: &DMatrix<Complex<T>>) -> DMatrix<Complex<T>> {
+        let mut res = data.clone();
+        self.process(&mut res, true);
+        res
+    }
+
+
+    fn process(&self, data: &mut DMatrix<Complex<T>>, inverse: bool) {
+        assert_eq!(data.shape(), (self.fft_col.len(), self.fft_row.len()), "input shape must equal the plan shape");
+
+        // 对每行做 fft，行不是连续存储的，先拷贝出来
+        let mut row_buf = Vec::with_capacity(data.ncols());
+        for i in 0..data.nrows() {
+            row_buf.clear();
+            row_buf.extend(data.row(i).iter().copied());
+            if inverse {
+                self.fft_row.ifft_inplace(&mut row_buf);
+            } else {
+                self.fft_row.fft_inplace(&mut row_buf);
+            }
+            for (j, v) in row_buf.iter().enumerate() {
+                data[(i, j)] = *v;
+            }
+        }
+        // 对每列做 fft
+        for mut column in data.column_iter_mut() {
+            if inverse {
+                self.fft_col.ifft_inplace(column.as_mut_slice());
+            } else {
+                self.fft_col.fft_inplace(column.as_mut_slice());
+            }
+        }
+    }
+}
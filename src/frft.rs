@@ -0,0 +1,295 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_1_SQRT_2, PI};
+use nalgebra::{convert, try_convert, Complex, DMatrix, RealField, SymmetricEigen};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use crate::fft::{expi, fftshift, ifftshift, Fft};
+
+/// `order mod 4`, in `[0, 4)`
+fn reduce_order<T: RealField + Copy>(order: T) -> f64 {
+    let a = try_convert::<T, f64>(order).unwrap() % 4.0;
+    if a < 0.0 { a + 4.0 } else { a }
+}
+
+/// Unitary DFT with the origin at index `N/2`, i.e. the FrFT of order 1 (or 3 if `inverse`)
+fn centered_dft<T: RealField + Copy>(data: &[Complex<T>], inverse: bool) -> Vec<Complex<T>> {
+    let n = data.len();
+    let fft = Fft::new(n);
+    let mut buf = ifftshift(data);
+    let scale: T = convert((n as f64).sqrt());
+    if inverse {
+        fft.ifft_inplace(&mut buf);
+        buf.iter_mut().for_each(|v| *v *= scale);
+    } else {
+        fft.fft_inplace(&mut buf);
+        buf.iter_mut().for_each(|v| *v = v.unscale(scale));
+    }
+    fftshift(&buf)
+}
+
+/// `x(t) → x(-t)` about the origin at index `N/2`, the FrFT of order 2
+fn reflect<T: Copy>(data: &[T]) -> Vec<T> {
+    let n = data.len();
+    let origin = n / 2;
+    (0..n).map(|i| data[(2 * origin + n - i) % n]).collect()
+}
+
+/// Full linear convolution of two complex sequences
+fn convolve_full<T: RealField + Copy>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    let len = a.len() + b.len() - 1;
+    let fft = Fft::new(len.next_power_of_two());
+    let spectrum = |x: &[Complex<T>]| {
+        let mut buf = vec![Complex::new(T::zero(), T::zero()); fft.len()];
+        buf[..x.len()].copy_from_slice(x);
+        fft.fft_inplace(&mut buf);
+        buf
+    };
+    let fb = spectrum(b);
+    let mut res: Vec<Complex<T>> = spectrum(a).iter().zip(&fb).map(|(x, y)| x * y).collect();
+    fft.ifft_inplace(&mut res);
+    res.truncate(len);
+    res
+}
+
+
+/// Fast fractional Fourier transform of order `order`, by the algorithm of
+/// Ozaktas, Arıkan, Kutay and Bozdağı (1996), in O(N log N).
+///
+/// The samples are taken as `x((n - N/2)/√N)`, i.e. centred on index `N/2` with spacing
+/// `1/√N`, so order 1 is `fftshift(fft(ifftshift(x)))/√N`, order 2 reverses time
+/// about `N/2`, and order 0 is the identity. Orders are taken modulo 4, and `-order`
+/// undoes `order`.
+///
+/// The general case interpolates the signal by 2 and evaluates the continuous FrFT
+/// integral with a chirp multiplication, a chirp convolution and another chirp
+/// multiplication. It is accurate for signals whose time-frequency support fits in
+/// the `√N × √N` box, but not exactly unitary or additive; see `DiscreteFrft` for that.
+pub fn frft<T: RealField + Copy>(signal: &[Complex<T>], order: T) -> Vec<Complex<T>> {
+    let n = signal.len();
+    let mut a = reduce_order(order);
+    if n <= 1 || a == 0.0 {
+        return signal.to_vec();
+    }
+    if a == 1.0 {
+        return centered_dft(signal, false);
+    }
+    if a == 2.0 {
+        return reflect(signal);
+    }
+    if a == 3.0 {
+        return centered_dft(signal, true);
+    }
+
+    // 利用 F^a = F^{a-1} F 把阶数归约到 [0.5, 1.5]
+    let mut f = signal.to_vec();
+    if a > 2.0 {
+        a -= 2.0;
+        f = reflect(&f);
+    }
+    if a > 1.5 {
+        a -= 1.0;
+        f = centered_dft(&f, false);
+    }
+    if a < 0.5 {
+        a += 1.0;
+        f = centered_dft(&f, true);
+    }
+
+    let zero = Complex::new(T::zero(), T::zero());
+    let alpha = a * PI / 2.0;
+    let tan_half = (alpha / 2.0).tan();
+    let sin_alpha = alpha.sin();
+
+    // 用 sinc 插值把采样率提高一倍，得到 2N-1 个样本
+    let mut upsampled = vec![zero; 2 * n - 1];
+    for (i, &v) in f.iter().enumerate() {
+        upsampled[2 * i] = v;
+    }
+    let half_width = 2 * n - 3;
+    let sinc: Vec<Complex<T>> = (0..2 * half_width + 1)
+        .map(|i| {
+            let x = (i as f64 - half_width as f64) / 2.0;
+            let v = if x == 0.0 { 1.0 } else { (PI * x).sin() / (PI * x) };
+            Complex::new(convert(v), T::zero())
+        })
+        .collect();
+    let interpolated = convolve_full(&upsampled, &sinc);
+
+    // 两端各补 N-1 个零，共 4N-3 个样本；原点（第 N/2 个样本）位于下标 N-1+2⌊N/2⌋
+    let len = 4 * n - 3;
+    let origin = (n - 1 + 2 * (n / 2)) as f64;
+    let mut g = vec![zero; len];
+    g[n - 1..3 * n - 2].copy_from_slice(&interpolated[half_width..half_width + 2 * n - 1]);
+    let chirp: Vec<Complex<T>> = (0..len)
+        .map(|i| {
+            let x = i as f64 - origin;
+            expi(-PI / n as f64 * tan_half / 4.0 * x * x)
+        })
+        .collect();
+    g.iter_mut().zip(&chirp).for_each(|(v, c)| *v *= *c);
+
+    let c = PI / n as f64 / sin_alpha / 4.0;
+    let kernel: Vec<Complex<T>> = (0..8 * n - 7)
+        .map(|i| {
+            let x = i as f64 - (4 * n - 4) as f64;
+            expi(c * x * x)
+        })
+        .collect();
+    let convolved = convolve_full(&kernel, &g);
+
+    let scale: Complex<T> = expi::<T>(-(1.0 - a) * PI / 4.0) * convert::<f64, T>((c / PI).sqrt());
+    (0..n)
+        .map(|i| {
+            let j = n - 1 + 2 * i;
+            convolved[4 * n - 4 + j] * chirp[j] * scale
+        })
+        .collect()
+}
+
+
+/// Discrete fractional Fourier transform plan (Candan, Kutay and Ozaktas, 2000).
+///
+/// `F^a = E·diag(e^{-iπka/2})·Eᵀ`, where the columns of `E` are the eigenvectors of the
+/// matrix `S` that commutes with the DFT, ordered as discrete Hermite–Gaussians of order
+/// `k = 0, 1, ..., N-2` and `N` (even `N`) or `N-1` (odd `N`). The result is exactly
+/// unitary and index-additive, `F^a F^b = F^{a+b}`, and order 1 is the centred unitary
+/// DFT, with the same sample convention as `frft`. The eigenvectors are computed once
+/// when planning, in O(N³); each transform is then a dense O(N²) product.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct DiscreteFrft<T: RealField = f32> {
+    /// 特征向量按列存放
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_matrix"))]
+    eigenvectors: DMatrix<T>,
+    /// Hermite–Gaussian order of each column
+    orders: Vec<usize>,
+}
+
+impl<T: RealField + Copy> DiscreteFrft<T> {
+    pub fn new(size: usize) -> Self {
+        let (eigenvectors, orders) = hermite_eigenvectors(size);
+        Self { eigenvectors: eigenvectors.map(|v| convert(v)), orders }
+    }
+
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+
+    /// Fractional Fourier transform of `data` with real order `order`
+    pub fn frft(&self, data: &[Complex<T>], order: T) -> Vec<Complex<T>> {
+        let n = self.len();
+        assert_eq!(data.len(), n, "input length must equal the plan size");
+        let a = reduce_order(order);
+
+        // 特征向量以下标 0 为原点，先把中心移过去
+        let shifted = ifftshift(data);
+        let zero = Complex::new(T::zero(), T::zero());
+        let coeffs: Vec<Complex<T>> = self
+            .eigenvectors
+            .column_iter()
+            .zip(&self.orders)
+            .map(|(column, &k)| {
+                let c = column.iter().zip(&shifted).fold(zero, |acc, (&e, &x)| acc + x * e);
+                c * expi::<T>(-PI / 2.0 * k as f64 * a)
+            })
+            .collect();
+        let res: Vec<Complex<T>> = self
+            .eigenvectors
+            .row_iter()
+            .map(|row| row.iter().zip(&coeffs).fold(zero, |acc, (&e, &c)| acc + c * e))
+            .collect();
+        fftshift(&res)
+    }
+
+    /// Inverse transform, i.e. the transform of order `-order`
+    pub fn ifrft(&self, data: &[Complex<T>], order: T) -> Vec<Complex<T>> {
+        self.frft(data, -order)
+    }
+}
+
+
+/// Eigenvectors of `S` and their Hermite–Gaussian orders.
+///
+/// `S = circ(1, 0, ..., 0, 1) + diag(2cos(2πn/N) - 4)` commutes with the DFT. Its
+/// eigenvalues can repeat across the even and odd subspaces, so it is split by the
+/// orthogonal matrix `P` into an even block and an odd block, which are diagonalized
+/// separately. Within each block, larger eigenvalues belong to lower orders.
+fn hermite_eigenvectors(n: usize) -> (DMatrix<f64>, Vec<usize>) {
+    if n == 0 {
+        return (DMatrix::zeros(0, 0), Vec::new());
+    }
+
+    let mut s = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            2.0 * (2.0 * PI * i as f64 / n as f64).cos() - 4.0
+        } else {
+            0.0
+        }
+    });
+    for i in 0..n {
+        s[(i, (i + 1) % n)] += 1.0;
+        s[((i + 1) % n, i)] += 1.0;
+    }
+    // P 把 x(n) 分成偶部 (x(n) + x(-n))/√2 和奇部 (x(n) - x(-n))/√2，P 对称且正交
+    let half = n / 2;
+    let even_len = half + 1;
+    let mut p = DMatrix::zeros(n, n);
+    p[(0, 0)] = 1.0;
+    for i in 1..n {
+        let j = n - i;
+        if i == j {
+            p[(i, i)] = 1.0;
+        } else if i < j {
+            p[(i, i)] = FRAC_1_SQRT_2;
+            p[(i, j)] = FRAC_1_SQRT_2;
+        } else {
+            p[(i, i)] = -FRAC_1_SQRT_2;
+            p[(i, j)] = FRAC_1_SQRT_2;
+        }
+    }
+    let cs = &p * &s * &p;
+
+    let block = |start: usize, len: usize| -> Vec<nalgebra::DVector<f64>> {
+        if len == 0 {
+            return Vec::new();
+        }
+        let eig = SymmetricEigen::new(cs.view((start, start), (len, len)).into_owned());
+        let mut index: Vec<usize> = (0..len).collect();
+        index.sort_by(|&a, &b| eig.eigenvalues[b].partial_cmp(&eig.eigenvalues[a]).unwrap());
+        index
+            .into_iter()
+            .map(|k| {
+                let mut padded = nalgebra::DVector::zeros(n);
+                padded.rows_mut(start, len).copy_from(&eig.eigenvectors.column(k));
+                &p * padded
+            })
+            .collect()
+    };
+    let even = block(0, even_len);
+    let odd = block(even_len, n - even_len);
+
+    // 偶数阶取偶特征向量、奇数阶取奇特征向量，交错排列；N 为偶数时最后一个偶向量对应阶数 N
+    let mut columns = Vec::with_capacity(n);
+    let mut orders = Vec::with_capacity(n);
+    let (mut e, mut o) = (even.into_iter(), odd.into_iter());
+    for k in 0..n {
+        let next = if k % 2 == 0 { e.next() } else { o.next() };
+        match next {
+            Some(v) => {
+                columns.push(v);
+                orders.push(k);
+            }
+            None => {
+                columns.push(e.next().expect("even eigenvector for order N"));
+                orders.push(n);
+            }
+        }
+    }
+    (DMatrix::from_columns(&columns), orders)
+}
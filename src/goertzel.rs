@@ -0,0 +1,147 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, try_convert, Complex, ComplexField, RealField};
+use crate::fft::expi;
+
+/// State of the recursion `s(n) = x(n) + 2cos(ω)·s(n-1) - s(n-2)` for one frequency
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Resonator<T> {
+    /// Normalized angular frequency `ω`, in radians per sample
+    omega: f64,
+    /// `2cos(ω)`
+    coeff: T,
+    /// `e^{-iω}`
+    twiddle: Complex<T>,
+    s1: T,
+    s2: T,
+}
+
+impl<T: RealField + Copy> Resonator<T> {
+    fn new(omega: f64) -> Self {
+        Self {
+            omega,
+            coeff: convert(2.0 * expi::<f64>(omega).re),
+            twiddle: expi(-omega),
+            s1: T::zero(),
+            s2: T::zero(),
+        }
+    }
+
+    fn push(&mut self, x: T) {
+        let s = x + self.coeff * self.s1 - self.s2;
+        self.s2 = self.s1;
+        self.s1 = s;
+    }
+
+    /// `Σ x(n) e^{-iωn}` over the `len` samples pushed so far; the state is cleared
+    fn finish(&mut self, len: usize) -> Complex<T> {
+        // y = s(N-1) - e^{-iω}·s(N-2) 等于 e^{iω(N-1)} 乘以 DTFT，
+        // 对非整数频点这个相位因子不能省略
+        let y = Complex::new(self.s1, T::zero()) - self.twiddle * self.s2;
+        self.s1 = T::zero();
+        self.s2 = T::zero();
+        y * expi::<T>(-self.omega * len.saturating_sub(1) as f64)
+    }
+}
+
+
+/// Spectrum of `signal` at bin `k` of its `N`-point DFT, i.e. `Σ x(n) e^{-2πikn/N}`.
+///
+/// `k` need not be an integer (generalized Goertzel), in which case this is the DTFT
+/// sampled between the DFT bins. For integer `k` the result equals `Fft::fft(signal)[k]`.
+/// It costs one multiplication per sample, so a few bins are cheaper than a full FFT.
+pub fn goertzel_bin<T: RealField + Copy>(signal: &[T], k: T) -> Complex<T> {
+    let k = try_convert::<T, f64>(k).unwrap();
+    let n = signal.len().max(1) as f64;
+    run(signal, 2.0 * PI * k / n)
+}
+
+/// Spectrum of `signal`, sampled at rate `fs`, at `frequency`: `Σ x(n) e^{-2πi·f·n/fs}`
+pub fn goertzel<T: RealField + Copy>(signal: &[T], frequency: T, fs: T) -> Complex<T> {
+    let f = try_convert::<T, f64>(frequency).unwrap();
+    let fs = try_convert::<T, f64>(fs).unwrap();
+    run(signal, 2.0 * PI * f / fs)
+}
+
+fn run<T: RealField + Copy>(signal: &[T], omega: f64) -> Complex<T> {
+    let mut resonator = Resonator::new(omega);
+    signal.iter().for_each(|&x| resonator.push(x));
+    resonator.finish(signal.len())
+}
+
+
+/// Measurement of one target frequency over one block
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tone<T> {
+    pub frequency: T,
+    /// `|X(f)|`; a sinusoid of amplitude `A` on the target frequency gives about `A·N/2`
+    pub magnitude: T,
+    /// `arg X(f)`, relative to the first sample of the block
+    pub phase: T,
+}
+
+
+/// Streaming Goertzel detector for a set of target frequencies.
+///
+/// Samples may be fed in pieces of any length; every `block_size` samples one
+/// `Tone` per target frequency is reported and the accumulators restart.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GoertzelDetector<T: RealField = f32> {
+    block_size: usize,
+    frequencies: Vec<T>,
+    resonators: Vec<Resonator<T>>,
+    /// 当前块中已经读入的样本数
+    count: usize,
+}
+
+impl<T: RealField + Copy> GoertzelDetector<T> {
+    /// Detect `frequencies` (in the same unit as the sample rate `fs`) over blocks of `block_size` samples
+    pub fn new(frequencies: &[T], fs: T, block_size: usize) -> Self {
+        assert!(block_size > 0, "block size must be positive");
+        let fs = try_convert::<T, f64>(fs).unwrap();
+        let resonators = frequencies
+            .iter()
+            .map(|&f| Resonator::new(2.0 * PI * try_convert::<T, f64>(f).unwrap() / fs))
+            .collect();
+        Self { block_size, frequencies: frequencies.to_vec(), resonators, count: 0 }
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    pub fn frequencies(&self) -> &[T] {
+        &self.frequencies
+    }
+
+    /// Feed samples; returns the measurements of every block completed by them, oldest first
+    pub fn process(&mut self, input: &[T]) -> Vec<Vec<Tone<T>>> {
+        let mut res = Vec::new();
+        for &x in input {
+            self.resonators.iter_mut().for_each(|r| r.push(x));
+            self.count += 1;
+            if self.count == self.block_size {
+                let block_size = self.block_size;
+                let tones = self.resonators.iter_mut().zip(&self.frequencies).map(|(r, &frequency)| {
+                    let value = r.finish(block_size);
+                    Tone { frequency, magnitude: value.modulus(), phase: value.argument() }
+                });
+                res.push(tones.collect());
+                self.count = 0;
+            }
+        }
+        res
+    }
+
+    /// Discard the partially accumulated block
+    pub fn reset(&mut self) {
+        self.resonators.iter_mut().for_each(|r| {
+            r.s1 = T::zero();
+            r.s2 = T::zero();
+        });
+        self.count = 0;
+    }
+}
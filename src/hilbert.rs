@@ -0,0 +1,130 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, Complex, ComplexField, RealField};
+use crate::fft::{Fft, RealFft};
+use crate::window::Window;
+
+/// Analytic signal `x + i·H{x}` of a real sequence, computed with the FFT.
+///
+/// The negative frequencies are removed and the positive ones doubled; DC (and Nyquist
+/// for even `N`) are kept as is. The real part of the result equals `signal`.
+/// As with any FFT method the sequence is treated as periodic, so the ends may ring.
+pub fn hilbert<T: RealField + Copy>(signal: &[T]) -> Vec<Complex<T>> {
+    let n = signal.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let half = RealFft::new(n).rfft(signal);
+    let two: T = convert(2.0);
+    let mut spectrum = vec![Complex::new(T::zero(), T::zero()); n];
+    for (k, v) in half.into_iter().enumerate() {
+        // 正频率加倍，零频和（偶数长度时的）奈奎斯特频率保持不变
+        spectrum[k] = if k == 0 || 2 * k == n { v } else { v * two };
+    }
+    Fft::new(n).ifft_inplace(&mut spectrum);
+    spectrum
+}
+
+/// Amplitude envelope `|x + i·H{x}|`
+pub fn envelope<T: RealField + Copy>(signal: &[T]) -> Vec<T> {
+    hilbert(signal).iter().map(|v| v.modulus()).collect()
+}
+
+/// Instantaneous phase of the analytic signal, unwrapped, in radians
+pub fn instantaneous_phase<T: RealField + Copy>(signal: &[T]) -> Vec<T> {
+    let wrapped: Vec<T> = hilbert(signal).iter().map(|v| v.argument()).collect();
+    unwrap_phase(&wrapped)
+}
+
+/// Instantaneous frequency, in the unit of the sample rate `fs`.
+///
+/// It is the first difference of the unwrapped phase, so the result has `N - 1` samples,
+/// the `i`-th one lying between samples `i` and `i + 1`.
+pub fn instantaneous_frequency<T: RealField + Copy>(signal: &[T], fs: T) -> Vec<T> {
+    let scale = fs / T::two_pi();
+    instantaneous_phase(signal).windows(2).map(|w| (w[1] - w[0]) * scale).collect()
+}
+
+/// Remove the `2π` jumps of a phase sequence, so that consecutive samples differ by at most `π`
+pub fn unwrap_phase<T: RealField + Copy>(phase: &[T]) -> Vec<T> {
+    let two_pi = T::two_pi();
+    let mut offset = T::zero();
+    let mut res = Vec::with_capacity(phase.len());
+    for (i, &p) in phase.iter().enumerate() {
+        if i > 0 {
+            let jump = p - phase[i - 1];
+            offset -= (jump / two_pi).round() * two_pi;
+        }
+        res.push(p + offset);
+    }
+    res
+}
+
+
+/// Streaming FIR Hilbert transformer.
+///
+/// The impulse response is the ideal `2/(πm)` for odd `m` (and 0 for even `m`), centred and
+/// tapered by a window. The taps are odd in number, so the filter is antisymmetric with
+/// an integer delay of `(taps - 1)/2` samples. `process` returns the analytic signal,
+/// pairing each filter output with the input delayed by the same amount.
+/// The response falls off near DC and Nyquist; more taps narrow those transition bands.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HilbertFir<T: RealField = f32> {
+    coefficients: Vec<T>,
+    /// 最近的输入，按环形缓冲存放
+    history: Vec<T>,
+    /// 下一个样本在 `history` 中的位置
+    pos: usize,
+}
+
+impl<T: RealField + Copy> HilbertFir<T> {
+    /// Design a transformer with `taps` coefficients (odd, at least 3), tapered by `window`
+    pub fn new(taps: usize, window: Window) -> Self {
+        assert!(taps >= 3 && taps % 2 == 1, "the number of taps must be odd and at least 3");
+        let center = (taps - 1) / 2;
+        let window = window.coefficients::<f64>(taps);
+        let coefficients = (0..taps)
+            .map(|k| {
+                let m = k as isize - center as isize;
+                if m % 2 == 0 { T::zero() } else { convert(2.0 / (PI * m as f64) * window[k]) }
+            })
+            .collect();
+        Self { coefficients, history: vec![T::zero(); taps], pos: 0 }
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Delay between input and output, in samples
+    pub fn delay(&self) -> usize {
+        (self.coefficients.len() - 1) / 2
+    }
+
+    /// Filter the next piece of the stream; one analytic sample is returned per input sample
+    pub fn process(&mut self, input: &[T]) -> Vec<Complex<T>> {
+        let taps = self.coefficients.len();
+        let delay = self.delay();
+        input
+            .iter()
+            .map(|&x| {
+                self.history[self.pos] = x;
+                // history[pos - k] 是 k 个样本之前的输入
+                let im = self.coefficients.iter().enumerate().fold(T::zero(), |acc, (k, &h)| {
+                    acc + h * self.history[(self.pos + taps - k) % taps]
+                });
+                let re = self.history[(self.pos + taps - delay) % taps];
+                self.pos = (self.pos + 1) % taps;
+                Complex::new(re, im)
+            })
+            .collect()
+    }
+
+    /// Clear the input history
+    pub fn reset(&mut self) {
+        self.history.iter_mut().for_each(|v| *v = T::zero());
+        self.pos = 0;
+    }
+}
@@ -10,7 +10,7 @@
 [![License](https://img.shields.io/crates/l/signal-transforms)](https://github.com/your-username/your-repo/blob/master/LICENSE)
 [![GitHub stars](https://img.shields.io/github/stars/guofei9987/signal-transforms.svg?style=social&label=Star)](https://github.com/guofei9987/signal-transforms)
 [![Forks](https://img.shields.io/github/forks/guofei9987/signal-transforms.svg?style=social&label=Fork)](https://github.com/guofei9987/signal-transforms/fork)
-![Rust](https://img.shields.io/badge/Rust-1.60+-orange.svg)
+![Rust](https://img.shields.io/badge/Rust-1.87+-orange.svg)
 [![Crates.io Downloads](https://img.shields.io/crates/d/signal-transforms)](https://crates.io/crates/signal-transforms)
 [![GitHub Discussions](https://img.shields.io/github/discussions/guofei9987/signal-transforms)](https://github.com/guofei9987/signal-transforms/discussions)
 
@@ -21,6 +21,28 @@
 - Inverse Discrete Cosine Transform (IDCT)
 - Two-Dimensional Discrete Cosine Transform (DCT2)
 - Inverse Two-Dimensional Discrete Cosine Transform (IDCT2)
+- Discrete Sine Transform (DST) and its inverse, in 1-D and 2-D
+- Fast Poisson solver with Neumann (DCT) or Dirichlet (DST) boundaries
+- `f32` or `f64` precision, and complex input (`dct_1d_complex`, `dct_2d_complex`, ...)
+- Fast Fourier Transform (FFT) of any length: radix-2/4 and mixed-radix plans, Bluestein for large primes, plus `fftfreq`/`fftshift`
+- Real-input FFT (`rfft`/`irfft`) with `N/2 + 1` bins, in 1-D and 2-D
+- Chirp-Z Transform (CZT) and `zoom_fft` for high-resolution spectra over a narrow band
+- Two-dimensional FFT (`Fft2D`) and phase-correlation image registration with windowing and sub-pixel peak refinement
+- FFT-based `convolve`/`correlate` (full, same and valid modes, 1-D and 2-D) and streaming overlap-add/overlap-save FIR filters
+- Goertzel algorithm (including non-integer bins) as a one-shot function and a streaming multi-tone detector
+- Number Theoretic Transform (`ntt`) over prime fields for exact modular convolution, with CRT combination of several primes
+- Discrete Hartley Transform (`Dht`, `Dht2D` with separable and true 2-D `cas` kernels), self-inverse and O(N log N)
+- Fast Walsh–Hadamard transform (`Wht`, `Wht2D`) with natural, sequency and dyadic ordering, including an exactly reversible integer variant
+- Hilbert transform: analytic signal, envelope, unwrapped instantaneous phase and frequency, and a streaming FIR Hilbert transformer
+- Fractional Fourier transform: fast O(N log N) chirp-based `frft` and the unitary, index-additive `DiscreteFrft`
+- Non-uniform FFT (`Nufft`, `Nufft2D`) of type 1 and 2 with Kaiser–Bessel or exponential-of-semicircle gridding and a user-set tolerance
+- Lomb–Scargle periodogram (`LombScargle`) for unevenly sampled data, standard or floating-mean, with `astropy`-style normalizations, false-alarm probabilities and a NUFFT-accelerated grid evaluation
+- Single-level 1-D discrete wavelet transform (`Dwt`) with Haar and Daubechies `db1`–`db20` filters (`Wavelet`) computed by spectral factorization, with exact reconstruction
+- Multi-level decomposition and reconstruction (`wavedec`, `waverec`) in the `pywt` extension modes, with `dwt_max_level` and flatten/unflatten of the coefficients for thresholding
+- 2-D discrete wavelet transform (`Dwt2D`) on `DMatrix` with `LL`/`LH`/`HL`/`HH` subbands, and multi-level `wavedec2`/`waverec2`, for any image size
+- Wavelet catalogue (`Wavelet::from_name`): Daubechies, symlets, coiflets, biorthogonal and reverse biorthogonal CDF wavelets and discrete Meyer, plus custom filter banks with orthogonality/biorthogonality checks
+- Shared signal extension modes (`ExtensionMode`: zero, constant, symmetric, reflect, periodic, periodization, smooth, antisymmetric) with `pad`/`pad2`, used by the wavelet transforms and the block-wise DCT tiling (`BlockDct2D`)
+- Stationary (undecimated) wavelet transform by the à-trous algorithm, `swt`/`iswt` in 1-D and `swt2`/`iswt2` in 2-D, with any wavelet of the catalogue and an averaging reconstruction
 - Future plans to support more signal processing algorithms
 
 ## Installation
@@ -32,6 +54,13 @@ Add the following dependency to your `Cargo.toml` file:
 signal-transforms = "0.1.3"
 ```
 
+Optional features:
+
+- `std` (default): disable it (`default-features = false`) for `no_std` targets; an allocator is still needed for the dynamically sized plans, and math falls back to `libm`. `Dct4x4` needs no heap at all
+- `serde`: `Serialize`/`Deserialize` for the transform plans (`Dct`, `Dct2D`, `Dct4x4`, `Dst`, `Dst2D`), so the precomputed tables can be shipped instead of rebuilt; works without `std`
+- `ndarray`: `Dct::dct_axis`, `Dct2D::dct_2d_view` and friends, which read `ArrayView`s and write into `ArrayViewMut`s of any memory order, along any axis
+- `dct_raw`: naive `f64` reference implementation of every DCT/DST above, for rectangular input, plus `accuracy_report` to measure the max/RMS error of a fast plan against it
+
 # How To Use
 
 ## Discrete Cosine Transform (DCT)
@@ -140,10 +169,39 @@ For more information, visit the [official documentation](https://github.com/your
 */
 
 
+#![cfg_attr(not(feature = "std"), no_std)]
+// README 中的示例沿用 #[test] 写法
+#![allow(clippy::test_attr_in_doctest)]
+
+extern crate alloc;
 
 pub mod dct;
+pub mod dst;
+pub mod poisson;
+pub mod fft;
+pub mod czt;
+pub mod window;
+pub mod registration;
+pub mod convolve;
+pub mod goertzel;
+pub mod ntt;
+pub mod dht;
+pub mod wht;
+pub mod hilbert;
+pub mod frft;
+pub mod nufft;
+pub mod lomb_scargle;
+pub mod wavelet;
+pub mod extension;
+pub mod dwt;
+pub mod swt;
 mod dct_s;
 mod dct_raw;
+mod wavelet_tables;
+#[cfg(feature = "ndarray")]
+mod nd;
+#[cfg(feature = "serde")]
+mod serde_matrix;
 
 #[cfg(feature = "dct_raw")]
 pub use dct_raw::dct_raw_algo;
\ No newline at end of file
@@ -0,0 +1,124 @@
+//! `ndarray` interoperability.
+//!
+//! The transforms here work on lanes, so the input and output can be C-ordered,
+//! F-ordered or arbitrarily strided views, and no `DMatrix` copy is made.
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, RealField};
+use ndarray::{Array2, ArrayView, ArrayView2, ArrayViewMut, ArrayViewMut2, Axis, Dimension, Zip};
+use crate::dct::{Dct, Dct2D};
+use crate::dst::{Dst, Dst2D};
+
+/// `output(u) = weight(u) * Σ_x input(x) * table(x, u)` for every lane along `axis`
+fn forward_lanes<T: RealField + Copy, D: Dimension>(
+    table: &DMatrix<T>,
+    weight: &[T],
+    input: ArrayView<T, D>,
+    mut output: ArrayViewMut<T, D>,
+    axis: Axis,
+) {
+    assert_eq!(input.shape(), output.shape(), "input and output must have the same shape");
+    assert_eq!(input.len_of(axis), table.nrows(), "length along the axis must equal the plan size");
+
+    Zip::from(input.lanes(axis))
+        .and(output.lanes_mut(axis))
+        .for_each(|src, mut dst| {
+            for (u, out) in dst.iter_mut().enumerate() {
+                let sum = src.iter().enumerate().fold(T::zero(), |acc, (x, &v)| acc + v * table[(x, u)]);
+                *out = weight[u] * sum;
+            }
+        });
+}
+
+/// `output(x) = Σ_u weight(u) * input(u) * table(x, u)` for every lane along `axis`
+fn inverse_lanes<T: RealField + Copy, D: Dimension>(
+    table: &DMatrix<T>,
+    weight: &[T],
+    input: ArrayView<T, D>,
+    mut output: ArrayViewMut<T, D>,
+    axis: Axis,
+) {
+    assert_eq!(input.shape(), output.shape(), "input and output must have the same shape");
+    assert_eq!(input.len_of(axis), table.nrows(), "length along the axis must equal the plan size");
+
+    Zip::from(input.lanes(axis))
+        .and(output.lanes_mut(axis))
+        .for_each(|src, mut dst| {
+            for (x, out) in dst.iter_mut().enumerate() {
+                *out = src.iter().enumerate().fold(T::zero(), |acc, (u, &v)| acc + weight[u] * v * table[(x, u)]);
+            }
+        });
+}
+
+
+impl<T: RealField + Copy> Dct<T> {
+    /// DCT-II of every lane of `input` along `axis`, written into `output`
+    pub fn dct_axis<D: Dimension>(&self, input: ArrayView<T, D>, output: ArrayViewMut<T, D>, axis: Axis) {
+        forward_lanes(&self.cosine_table, self.alpha_table.as_slice(), input, output, axis);
+    }
+
+    /// IDCT-III of every lane of `input` along `axis`, written into `output`
+    pub fn idct_axis<D: Dimension>(&self, input: ArrayView<T, D>, output: ArrayViewMut<T, D>, axis: Axis) {
+        inverse_lanes(&self.cosine_table, self.alpha_table.as_slice(), input, output, axis);
+    }
+}
+
+
+impl<T: RealField + Copy> Dst<T> {
+    /// DST-II of every lane of `input` along `axis`, written into `output`
+    pub fn dst_axis<D: Dimension>(&self, input: ArrayView<T, D>, output: ArrayViewMut<T, D>, axis: Axis) {
+        forward_lanes(&self.sine_table, self.beta_table.as_slice(), input, output, axis);
+    }
+
+    /// IDST-III of every lane of `input` along `axis`, written into `output`
+    pub fn idst_axis<D: Dimension>(&self, input: ArrayView<T, D>, output: ArrayViewMut<T, D>, axis: Axis) {
+        inverse_lanes(&self.sine_table, self.beta_table.as_slice(), input, output, axis);
+    }
+}
+
+
+impl<T: RealField + Copy> Dct2D<T> {
+    /// Two-dimensional DCT-II of `input`, written into `output`
+    pub fn dct_2d_view(&self, input: ArrayView2<T>, output: ArrayViewMut2<T>) {
+        let weight_row: Vec<T> = self.alpha_table_row.row(0).iter().copied().collect();
+        let weight_col: Vec<T> = self.alpha_table_transpose_col.column(0).iter().copied().collect();
+
+        // 对每行做 dct
+        let mut tmp = Array2::zeros(input.raw_dim());
+        forward_lanes(&self.cosine_table_row, &weight_row, input, tmp.view_mut(), Axis(1));
+        // 对每列做 dct
+        forward_lanes(&self.cosine_table_col, &weight_col, tmp.view(), output, Axis(0));
+    }
+
+    /// Two-dimensional IDCT-III of `input`, written into `output`
+    pub fn idct_2d_view(&self, input: ArrayView2<T>, output: ArrayViewMut2<T>) {
+        let weight_row: Vec<T> = self.alpha_table_row.row(0).iter().copied().collect();
+        let weight_col: Vec<T> = self.alpha_table_transpose_col.column(0).iter().copied().collect();
+
+        let mut tmp = Array2::zeros(input.raw_dim());
+        inverse_lanes(&self.cosine_table_col, &weight_col, input, tmp.view_mut(), Axis(0));
+        inverse_lanes(&self.cosine_table_row, &weight_row, tmp.view(), output, Axis(1));
+    }
+}
+
+
+impl<T: RealField + Copy> Dst2D<T> {
+    /// Two-dimensional DST-II of `input`, written into `output`
+    pub fn dst_2d_view(&self, input: ArrayView2<T>, output: ArrayViewMut2<T>) {
+        let weight_row: Vec<T> = self.beta_table_row.row(0).iter().copied().collect();
+        let weight_col: Vec<T> = self.beta_table_transpose_col.column(0).iter().copied().collect();
+
+        let mut tmp = Array2::zeros(input.raw_dim());
+        forward_lanes(&self.sine_table_row, &weight_row, input, tmp.view_mut(), Axis(1));
+        forward_lanes(&self.sine_table_col, &weight_col, tmp.view(), output, Axis(0));
+    }
+
+    /// Two-dimensional IDST-III of `input`, written into `output`
+    pub fn idst_2d_view(&self, input: ArrayView2<T>, output: ArrayViewMut2<T>) {
+        let weight_row: Vec<T> = self.beta_table_row.row(0).iter().copied().collect();
+        let weight_col: Vec<T> = self.beta_table_transpose_col.column(0).iter().copied().collect();
+
+        let mut tmp = Array2::zeros(input.raw_dim());
+        inverse_lanes(&self.sine_table_col, &weight_col, input, tmp.view_mut(), Axis(0));
+        inverse_lanes(&self.sine_table_row, &weight_row, tmp.view(), output, Axis(1));
+    }
+}
@@ -0,0 +1,233 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// `998244353 = 119·2²³ + 1`, the usual NTT prime, with primitive root 3
+pub const MOD_998244353: u64 = 998_244_353;
+
+/// `(modulus, primitive root)` of the primes used by `convolve_exact`.
+///
+/// All of them support lengths up to `2²³`, and their product is about `7.9·10²⁵`.
+pub const CRT_PRIMES: [(u64, u64); 3] = [
+    (998_244_353, 3), // 119·2²³ + 1
+    (167_772_161, 3), // 5·2²⁵ + 1
+    (469_762_049, 3), // 7·2²⁶ + 1
+];
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    res
+}
+
+/// Inverse modulo a prime, by Fermat's little theorem
+fn inv_mod(a: u64, m: u64) -> u64 {
+    pow_mod(a, m - 2, m)
+}
+
+
+/// Number Theoretic Transform plan, the DFT over the field of integers modulo a prime.
+///
+/// The arithmetic is exact, so a product of spectra gives the cyclic convolution
+/// modulo `p` without rounding error. The length must be a power of two dividing `p - 1`.
+///
+/// Formula:
+/// ```latex
+/// X(k) = \sum_{n=0}^{N-1} x(n) \omega^{nk} \bmod p, \quad \omega = g^{(p-1)/N}
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ntt {
+    size: usize,
+    modulus: u64,
+    /// `ω^k` for `k` in `0..N/2`
+    twiddles: Vec<u64>,
+    /// `ω^{-k}` for `k` in `0..N/2`
+    inv_twiddles: Vec<u64>,
+    /// `N^{-1} mod p`
+    size_inv: u64,
+}
+
+impl Ntt {
+    /// Plan a transform of `size` points modulo `998244353`
+    pub fn new(size: usize) -> Self {
+        Self::with_modulus(size, MOD_998244353, 3)
+    }
+
+    /// Plan a transform modulo the prime `modulus`, given one of its primitive roots.
+    ///
+    /// The modulus must be below `2⁶³`; neither primality nor the root is checked.
+    pub fn with_modulus(size: usize, modulus: u64, root: u64) -> Self {
+        assert!(size == 0 || size.is_power_of_two(), "size must be a power of two");
+        assert!(modulus > 2 && modulus < 1 << 63, "modulus must be an odd prime below 2^63");
+        assert!(size == 0 || (modulus - 1).is_multiple_of(size as u64), "size must divide modulus - 1");
+        let n = size.max(1) as u64;
+        let w = pow_mod(root, (modulus - 1) / n, modulus);
+        let w_inv = inv_mod(w, modulus);
+        let powers = |base: u64| {
+            let mut cur = 1;
+            (0..size / 2)
+                .map(|_| {
+                    let res = cur;
+                    cur = mul_mod(cur, base, modulus);
+                    res
+                })
+                .collect()
+        };
+        Self {
+            size,
+            modulus,
+            twiddles: powers(w),
+            inv_twiddles: powers(w_inv),
+            size_inv: inv_mod(n % modulus, modulus),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+
+    /// Forward transform; inputs may be any `u64`, they are reduced first
+    pub fn ntt(&self, data: &[u64]) -> Vec<u64> {
+        let mut res = data.to_vec();
+        self.ntt_inplace(&mut res);
+        res
+    }
+
+    /// Inverse transform, scaled by `N^{-1}` so that `intt(ntt(x)) = x mod p`
+    pub fn intt(&self, data: &[u64]) -> Vec<u64> {
+        let mut res = data.to_vec();
+        self.intt_inplace(&mut res);
+        res
+    }
+
+    pub fn ntt_inplace(&self, data: &mut [u64]) {
+        self.process(data, &self.twiddles);
+    }
+
+    pub fn intt_inplace(&self, data: &mut [u64]) {
+        self.process(data, &self.inv_twiddles);
+        data.iter_mut().for_each(|v| *v = mul_mod(*v, self.size_inv, self.modulus));
+    }
+
+    /// 迭代的基 2 Cooley-Tukey：先位反转重排，再逐级蝶形
+    fn process(&self, data: &mut [u64], twiddles: &[u64]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        let n = self.size;
+        let p = self.modulus;
+        data.iter_mut().for_each(|v| *v %= p);
+        if n <= 1 {
+            return;
+        }
+
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                data.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            for chunk in data.chunks_exact_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(len / 2);
+                for (k, (a, b)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                    let t = mul_mod(*b, twiddles[k * step], p);
+                    *b = if *a >= t { *a - t } else { *a + p - t };
+                    *a = if *a + t >= p { *a + t - p } else { *a + t };
+                }
+            }
+            len <<= 1;
+        }
+    }
+}
+
+
+/// Linear convolution of `a` and `b` modulo the prime `modulus` with primitive root `root`.
+///
+/// The full `N + M - 1` outputs are returned; the padded length must divide `modulus - 1`.
+pub fn convolve_mod(a: &[u64], b: &[u64], modulus: u64, root: u64) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let ntt = Ntt::with_modulus(len.next_power_of_two(), modulus, root);
+    let spectrum = |x: &[u64]| {
+        let mut buf = vec![0; ntt.len()];
+        buf[..x.len()].copy_from_slice(x);
+        ntt.ntt_inplace(&mut buf);
+        buf
+    };
+    let fb = spectrum(b);
+    let mut res: Vec<u64> = spectrum(a).iter().zip(&fb).map(|(&x, &y)| mul_mod(x, y, modulus)).collect();
+    ntt.intt_inplace(&mut res);
+    res.truncate(len);
+    res
+}
+
+
+/// The unique `x` in `[0, Π m_i)` with `x ≡ r_i (mod m_i)`, by Garner's algorithm.
+///
+/// The moduli must be pairwise coprime primes whose product fits in a `u128`.
+pub fn crt(residues: &[u64], moduli: &[u64]) -> u128 {
+    assert_eq!(residues.len(), moduli.len(), "one residue per modulus");
+    let mut x: u128 = 0;
+    let mut product: u128 = 1;
+    for (&r, &m) in residues.iter().zip(moduli) {
+        // x + t·product ≡ r (mod m)
+        // 模数可以超过 2⁶³，在 u128 中相减以免溢出
+        let x_mod = x % m as u128;
+        let diff = (((r % m) as u128 + m as u128 - x_mod) % m as u128) as u64;
+        let t = mul_mod(diff, inv_mod((product % m as u128) as u64, m), m);
+        // 先检查新的乘积：x < product，所以 x + t·product < product·m 也不会溢出
+        let next = product.checked_mul(m as u128).expect("product of moduli overflows u128");
+        x += t as u128 * product;
+        product = next;
+    }
+    x
+}
+
+
+/// Exact linear convolution of non-negative integers, combined from three NTT primes by CRT.
+///
+/// Correct as long as every output is below the product of `CRT_PRIMES` (about `7.9·10²⁵`),
+/// e.g. `min(N, M)·max(a)·max(b) < 7.9·10²⁵`.
+pub fn convolve_exact(a: &[u64], b: &[u64]) -> Vec<u128> {
+    let parts: Vec<Vec<u64>> = CRT_PRIMES
+        .iter()
+        .map(|&(p, g)| {
+            let ra: Vec<u64> = a.iter().map(|v| v % p).collect();
+            let rb: Vec<u64> = b.iter().map(|v| v % p).collect();
+            convolve_mod(&ra, &rb, p, g)
+        })
+        .collect();
+    let moduli: Vec<u64> = CRT_PRIMES.iter().map(|&(p, _)| p).collect();
+    let len = parts[0].len();
+    (0..len)
+        .map(|i| {
+            let residues: Vec<u64> = parts.iter().map(|part| part[i]).collect();
+            crt(&residues, &moduli)
+        })
+        .collect()
+}
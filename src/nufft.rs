@@ -0,0 +1,336 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, try_convert, Complex, DMatrix, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use crate::fft::{Fft, Fft2D};
+
+/// Upsampling factor of the fine grid
+const SIGMA: f64 = 2.0;
+
+/// Spreading kernel used for gridding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NufftKernel {
+    /// `I₀(β√(1 - z²))`, with `β` from Beatty et al. (2005)
+    KaiserBessel,
+    /// `exp(β(√(1 - z²) - 1))`, the "exponential of semicircle" of Barnett et al. (2019)
+    ExpSemicircle,
+}
+
+/// Modified Bessel function of the first kind, order 0, by its power series
+fn bessel_i0(x: f64) -> f64 {
+    let q = x * x / 4.0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-17 {
+        term *= q / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+/// Nodes and weights of the `q`-point Gauss–Legendre rule on `[-1, 1]`
+fn gauss_legendre(q: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut nodes = Vec::with_capacity(q);
+    let mut weights = Vec::with_capacity(q);
+    for i in 0..q {
+        // 以 Chebyshev 近似为初值做 Newton 迭代
+        let mut z = (PI * (i as f64 + 0.75) / (q as f64 + 0.5)).cos();
+        let mut derivative = 1.0;
+        for _ in 0..100 {
+            let (mut p0, mut p1) = (1.0, z);
+            for k in 2..=q {
+                let p2 = ((2 * k - 1) as f64 * z * p1 - (k - 1) as f64 * p0) / k as f64;
+                p0 = p1;
+                p1 = p2;
+            }
+            derivative = q as f64 * (z * p1 - p0) / (z * z - 1.0);
+            let step = p1 / derivative;
+            z -= step;
+            if step.abs() < 1e-16 {
+                break;
+            }
+        }
+        nodes.push(z);
+        weights.push(2.0 / ((1.0 - z * z) * derivative * derivative));
+    }
+    (nodes, weights)
+}
+
+
+/// Kernel and fine grid along one axis
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Gridding {
+    kernel: NufftKernel,
+    /// 核覆盖的细网格点数
+    width: usize,
+    beta: f64,
+    modes: usize,
+    grid: usize,
+    /// `h / ψ̂(k)` for the modes `k = -M/2, ..., (M-1)/2`, `h = 2π / grid`
+    correction: Vec<f64>,
+}
+
+impl Gridding {
+    fn new(modes: usize, tolerance: f64, kernel: NufftKernel) -> Self {
+        assert!(tolerance > 0.0 && tolerance < 1.0, "tolerance must be in (0, 1)");
+        let width = ((1.0 / tolerance).log10().ceil() as usize + 1).clamp(2, 16);
+        let beta = match kernel {
+            NufftKernel::KaiserBessel => {
+                let w = width as f64;
+                PI * ((w / SIGMA).powi(2) * (SIGMA - 0.5).powi(2) - 0.8).sqrt()
+            }
+            NufftKernel::ExpSemicircle => 2.30 * width as f64,
+        };
+        let target = ((SIGMA * modes as f64).ceil() as usize).max(2 * width);
+        let grid = next_smooth_even(target);
+
+        let mut res = Self { kernel, width, beta, modes, grid, correction: Vec::new() };
+        res.correction = res.kernel_correction();
+        res
+    }
+
+    /// `φ(z)` on `[-1, 1]`
+    fn phi(&self, z: f64) -> f64 {
+        let s = 1.0 - z * z;
+        if s <= 0.0 {
+            return 0.0;
+        }
+        match self.kernel {
+            NufftKernel::KaiserBessel => bessel_i0(self.beta * s.sqrt()) / bessel_i0(self.beta),
+            NufftKernel::ExpSemicircle => (self.beta * (s.sqrt() - 1.0)).exp(),
+        }
+    }
+
+    /// `h / ψ̂(k)`, where `ψ(x) = φ(2x / (w h))` and its Fourier transform is found by quadrature
+    fn kernel_correction(&self) -> Vec<f64> {
+        let h = 2.0 * PI / self.grid as f64;
+        let half_support = self.width as f64 * h / 2.0;
+        let (nodes, weights) = gauss_legendre(4 * self.width + 20);
+        let values: Vec<f64> = nodes.iter().map(|&z| self.phi(z)).collect();
+        mode_indices(self.modes)
+            .map(|k| {
+                let ft: f64 = nodes
+                    .iter()
+                    .zip(&weights)
+                    .zip(&values)
+                    .map(|((&z, &w), &v)| w * v * (k as f64 * z * half_support).cos())
+                    .sum::<f64>()
+                    * half_support;
+                h / ft
+            })
+            .collect()
+    }
+
+    /// First fine-grid index covered by the kernel centred at `x`, and the `w` kernel values
+    fn weights<T: RealField + Copy>(&self, x: T) -> (isize, Vec<f64>) {
+        let h = 2.0 * PI / self.grid as f64;
+        let x = try_convert::<T, f64>(x).unwrap() / h;
+        let start = (x - self.width as f64 / 2.0).ceil();
+        let scale = 2.0 / self.width as f64;
+        let values = (0..self.width).map(|i| self.phi((start + i as f64 - x) * scale)).collect();
+        (start as isize, values)
+    }
+
+    /// Fine-grid index of mode `k`
+    fn bin(&self, k: isize) -> usize {
+        k.rem_euclid(self.grid as isize) as usize
+    }
+}
+
+/// Modes `-M/2, ..., (M-1)/2` in increasing order
+fn mode_indices(modes: usize) -> impl Iterator<Item = isize> {
+    let first = -((modes / 2) as isize);
+    (0..modes as isize).map(move |i| first + i)
+}
+
+/// Smallest even `2^a·3^b·5^c ≥ n`, so the fine-grid FFT stays on fast radices
+fn next_smooth_even(n: usize) -> usize {
+    let mut m = n.max(2) + n % 2;
+    loop {
+        let mut r = m;
+        for p in [2, 3, 5] {
+            while r.is_multiple_of(p) {
+                r /= p;
+            }
+        }
+        if r == 1 {
+            return m;
+        }
+        m += 2;
+    }
+}
+
+
+/// One-dimensional non-uniform FFT plan for `M` Fourier modes.
+///
+/// Type 1 (non-uniform → uniform) and type 2 (uniform → non-uniform):
+/// ```latex
+/// f_k = \sum_{j} c_j e^{-i k x_j}, \qquad c_j = \sum_{k} f_k e^{i k x_j},
+/// \qquad k = -\lfloor M/2 \rfloor, \dots, \lceil M/2 \rceil - 1
+/// ```
+/// Type 2 is the adjoint of type 1. The points `x_j` are taken modulo `2π`.
+/// Each point is spread onto a 2× oversampled grid with a kernel of `w` points,
+/// the grid is transformed by FFT, and the kernel's Fourier transform is divided out.
+/// `w` is picked from `tolerance`, the relative ℓ² error aimed for.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nufft<T: RealField = f32> {
+    gridding: Gridding,
+    fft: Fft<T>,
+}
+
+impl<T: RealField + Copy> Nufft<T> {
+    pub fn new(modes: usize, tolerance: T, kernel: NufftKernel) -> Self {
+        let gridding = Gridding::new(modes, try_convert::<T, f64>(tolerance).unwrap(), kernel);
+        let fft = Fft::new(gridding.grid);
+        Self { gridding, fft }
+    }
+
+    /// Number of Fourier modes `M`
+    pub fn modes(&self) -> usize {
+        self.gridding.modes
+    }
+
+    /// Kernel width `w`, in fine-grid points
+    pub fn width(&self) -> usize {
+        self.gridding.width
+    }
+
+
+    /// Type 1: the `M` Fourier coefficients of the point sources `strengths` at `points`
+    pub fn type1(&self, points: &[T], strengths: &[Complex<T>]) -> Vec<Complex<T>> {
+        assert_eq!(points.len(), strengths.len(), "one strength per point");
+        let g = &self.gridding;
+        let n = g.grid as isize;
+        let mut grid = vec![Complex::new(T::zero(), T::zero()); g.grid];
+        // 把每个点按核函数分摊到细网格上（周期延拓）
+        for (&x, &c) in points.iter().zip(strengths) {
+            let (start, weights) = g.weights(x);
+            for (i, w) in weights.into_iter().enumerate() {
+                grid[(start + i as isize).rem_euclid(n) as usize] += c * convert::<f64, T>(w);
+            }
+        }
+        self.fft.fft_inplace(&mut grid);
+        mode_indices(g.modes)
+            .zip(&g.correction)
+            .map(|(k, &corr)| grid[g.bin(k)] * convert::<f64, T>(corr))
+            .collect()
+    }
+
+    /// Type 2: the Fourier series with coefficients `coefficients`, evaluated at `points`
+    pub fn type2(&self, points: &[T], coefficients: &[Complex<T>]) -> Vec<Complex<T>> {
+        let g = &self.gridding;
+        assert_eq!(coefficients.len(), g.modes, "one coefficient per mode");
+        let n = g.grid as isize;
+        let mut grid = vec![Complex::new(T::zero(), T::zero()); g.grid];
+        for ((k, &corr), &f) in mode_indices(g.modes).zip(&g.correction).zip(coefficients) {
+            grid[g.bin(k)] = f * convert::<f64, T>(corr);
+        }
+        // 未归一化的逆变换
+        self.fft.ifft_inplace(&mut grid);
+        let scale: T = convert(g.grid as f64);
+        points
+            .iter()
+            .map(|&x| {
+                let (start, weights) = g.weights(x);
+                let sum = weights.into_iter().enumerate().fold(Complex::new(T::zero(), T::zero()), |acc, (i, w)| {
+                    acc + grid[(start + i as isize).rem_euclid(n) as usize] * convert::<f64, T>(w)
+                });
+                sum * scale
+            })
+            .collect()
+    }
+}
+
+
+/// Two-dimensional non-uniform FFT plan for `M₁ × M₂` Fourier modes.
+///
+/// Same definitions as `Nufft` with `k·x = k₁x_j + k₂y_j`; the modes are returned as an
+/// `M₁ × M₂` matrix, row `k₁` and column `k₂`, each in increasing order.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nufft2D<T: RealField = f32> {
+    gridding_row: Gridding,
+    gridding_col: Gridding,
+    fft: Fft2D<T>,
+}
+
+impl<T: RealField + Copy> Nufft2D<T> {
+    pub fn new(modes_row: usize, modes_col: usize, tolerance: T, kernel: NufftKernel) -> Self {
+        let tolerance = try_convert::<T, f64>(tolerance).unwrap();
+        let gridding_row = Gridding::new(modes_row, tolerance, kernel);
+        let gridding_col = Gridding::new(modes_col, tolerance, kernel);
+        let fft = Fft2D::new(gridding_row.grid, gridding_col.grid);
+        Self { gridding_row, gridding_col, fft }
+    }
+
+    /// Number of Fourier modes `(M₁, M₂)`
+    pub fn modes(&self) -> (usize, usize) {
+        (self.gridding_row.modes, self.gridding_col.modes)
+    }
+
+
+    /// Type 1: the `M₁ × M₂` Fourier coefficients of the point sources at `(x_j, y_j)`
+    pub fn type1(&self, x: &[T], y: &[T], strengths: &[Complex<T>]) -> DMatrix<Complex<T>> {
+        assert!(x.len() == y.len() && x.len() == strengths.len(), "one coordinate pair and strength per point");
+        let (gr, gc) = (&self.gridding_row, &self.gridding_col);
+        let (nr, nc) = (gr.grid as isize, gc.grid as isize);
+        let mut grid = DMatrix::from_element(gr.grid, gc.grid, Complex::new(T::zero(), T::zero()));
+        for ((&px, &py), &c) in x.iter().zip(y).zip(strengths) {
+            let (start_r, weights_r) = gr.weights(px);
+            let (start_c, weights_c) = gc.weights(py);
+            for (j, &wc) in weights_c.iter().enumerate() {
+                let col = (start_c + j as isize).rem_euclid(nc) as usize;
+                let cw = c * convert::<f64, T>(wc);
+                for (i, &wr) in weights_r.iter().enumerate() {
+                    grid[((start_r + i as isize).rem_euclid(nr) as usize, col)] += cw * convert::<f64, T>(wr);
+                }
+            }
+        }
+        let spectrum = self.fft.fft_2d(&grid);
+
+        let rows: Vec<(usize, f64)> = mode_indices(gr.modes).map(|k| gr.bin(k)).zip(gr.correction.iter().copied()).collect();
+        let cols: Vec<(usize, f64)> = mode_indices(gc.modes).map(|k| gc.bin(k)).zip(gc.correction.iter().copied()).collect();
+        DMatrix::from_fn(gr.modes, gc.modes, |i, j| {
+            spectrum[(rows[i].0, cols[j].0)] * convert::<f64, T>(rows[i].1 * cols[j].1)
+        })
+    }
+
+    /// Type 2: the 2-D Fourier series with `M₁ × M₂` coefficients, evaluated at `(x_j, y_j)`
+    pub fn type2(&self, x: &[T], y: &[T], coefficients: &DMatrix<Complex<T>>) -> Vec<Complex<T>> {
+        assert_eq!(x.len(), y.len(), "one coordinate pair per point");
+        let (gr, gc) = (&self.gridding_row, &self.gridding_col);
+        assert_eq!(coefficients.shape(), (gr.modes, gc.modes), "coefficients must be M₁ × M₂");
+        let (nr, nc) = (gr.grid as isize, gc.grid as isize);
+
+        let mut spectrum = DMatrix::from_element(gr.grid, gc.grid, Complex::new(T::zero(), T::zero()));
+        for (j, (kc, &corr_c)) in mode_indices(gc.modes).zip(&gc.correction).enumerate() {
+            for (i, (kr, &corr_r)) in mode_indices(gr.modes).zip(&gr.correction).enumerate() {
+                spectrum[(gr.bin(kr), gc.bin(kc))] = coefficients[(i, j)] * convert::<f64, T>(corr_r * corr_c);
+            }
+        }
+        let scale: T = convert((gr.grid * gc.grid) as f64);
+        let grid = self.fft.ifft_2d(&spectrum);
+
+        x.iter()
+            .zip(y)
+            .map(|(&px, &py)| {
+                let (start_r, weights_r) = gr.weights(px);
+                let (start_c, weights_c) = gc.weights(py);
+                let mut sum = Complex::new(T::zero(), T::zero());
+                for (j, &wc) in weights_c.iter().enumerate() {
+                    let col = (start_c + j as isize).rem_euclid(nc) as usize;
+                    let inner = weights_r.iter().enumerate().fold(Complex::new(T::zero(), T::zero()), |acc, (i, &wr)| {
+                        acc + grid[((start_r + i as isize).rem_euclid(nr) as usize, col)] * convert::<f64, T>(wr)
+                    });
+                    sum += inner * convert::<f64, T>(wc);
+                }
+                sum * scale
+            })
+            .collect()
+    }
+}
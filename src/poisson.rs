@@ -0,0 +1,62 @@
+use nalgebra::{convert, DMatrix, RealField};
+use crate::dct::Dct2D;
+use crate::dst::Dst2D;
+
+/// Eigenvalue of the 1-D second difference `u(x-1) - 2u(x) + u(x+1)`
+fn laplacian_eigenvalue<T: RealField + Copy>(k: usize, size: usize) -> T {
+    let two: T = convert(2.0);
+    two * (T::pi() * convert(k as f64) / convert(size as f64)).cos() - two
+}
+
+
+/// Solve the discrete Poisson equation `∇²u = f` with Neumann boundaries.
+///
+/// The grid is cell-centered and the boundary lies half a sample outside it,
+/// i.e. `u(-1) = u(0)` and `u(N) = u(N - 1)` on every side.
+/// The Laplacian is diagonal in the DCT-II basis, with eigenvalues
+/// ```latex
+/// \lambda(u, v) = 2\cos\left(\frac{u\pi}{M}\right) + 2\cos\left(\frac{v\pi}{N}\right) - 4
+/// ```
+///
+/// `\lambda(0, 0) = 0`, so the problem only has a solution when `f` has zero mean,
+/// and then only up to a constant.
+/// The mean of `f` is discarded and the returned `u` has zero mean.
+pub fn poisson_solve_neumann<T: RealField + Copy>(f: &DMatrix<T>) -> DMatrix<T> {
+    let (row, col) = f.shape();
+    let dct = Dct2D::new(row, col);
+    let mut coef = dct.dct_2d(f);
+    for i in 0..row {
+        for j in 0..col {
+            if i == 0 && j == 0 {
+                // 奇异的零频分量，固定解的均值为 0
+                coef[(i, j)] = T::zero();
+            } else {
+                coef[(i, j)] /= laplacian_eigenvalue::<T>(i, row) + laplacian_eigenvalue::<T>(j, col);
+            }
+        }
+    }
+    dct.idct_2d(&coef)
+}
+
+
+/// Solve the discrete Poisson equation `∇²u = f` with homogeneous Dirichlet boundaries.
+///
+/// The grid is cell-centered and `u` vanishes half a sample outside it,
+/// i.e. `u(-1) = -u(0)` and `u(N) = -u(N - 1)` on every side.
+/// The Laplacian is diagonal in the DST-II basis, with eigenvalues
+/// ```latex
+/// \lambda(u, v) = 2\cos\left(\frac{(u + 1)\pi}{M}\right) + 2\cos\left(\frac{(v + 1)\pi}{N}\right) - 4
+/// ```
+///
+/// Non-zero boundary values can be handled by moving them into `f`.
+pub fn poisson_solve_dirichlet<T: RealField + Copy>(f: &DMatrix<T>) -> DMatrix<T> {
+    let (row, col) = f.shape();
+    let dst = Dst2D::new(row, col);
+    let mut coef = dst.dst_2d(f);
+    for i in 0..row {
+        for j in 0..col {
+            coef[(i, j)] /= laplacian_eigenvalue::<T>(i + 1, row) + laplacian_eigenvalue::<T>(j + 1, col);
+        }
+    }
+    dst.idst_2d(&coef)
+}
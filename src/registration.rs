@@ -0,0 +1,141 @@
+use core::f64::consts::PI;
+use nalgebra::{convert, Complex, ComplexField, DMatrix, RealField};
+use crate::fft::{expi, Fft2D};
+use crate::window::Window;
+
+/// Translation found by `phase_correlate`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseCorrelation<T> {
+    /// Shift along the rows (vertical), in pixels
+    pub row_shift: T,
+    /// Shift along the columns (horizontal), in pixels
+    pub col_shift: T,
+    /// Height of the correlation peak, close to 1 for a clean translation and near 0 for unrelated images
+    pub peak: T,
+}
+
+
+/// Sub-pixel offset of a peak from three samples, by fitting a parabola
+fn parabolic_offset<T: RealField + Copy>(left: T, center: T, right: T) -> T {
+    let denom = left - center * convert(2.0) + right;
+    if denom.abs() <= T::default_epsilon() {
+        return T::zero();
+    }
+    let offset = (left - right) / (denom * convert(2.0));
+    offset.clamp(convert(-0.5), convert(0.5))
+}
+
+/// Frequency of DFT bin `k` in cycles per sample, in `(-1/2, 1/2]`
+fn signed_freq(k: usize, n: usize) -> f64 {
+    (if k > n / 2 { k as f64 - n as f64 } else { k as f64 }) / n as f64
+}
+
+/// Rows of `e^{2πi·f_k·x}` for the positions `center + (p - UPSAMPLE) / UPSAMPLE`
+fn upsampled_kernel<T: RealField + Copy>(center: f64, n: usize) -> DMatrix<Complex<T>> {
+    DMatrix::from_fn(2 * UPSAMPLE + 1, n, |p, k| {
+        let x = center + (p as f64 - UPSAMPLE as f64) / UPSAMPLE as f64;
+        expi(2.0 * PI * signed_freq(k, n) * x)
+    })
+}
+
+/// The peak is refined on a grid `1/UPSAMPLE` pixel apart, within one pixel of the integer peak
+const UPSAMPLE: usize = 20;
+
+
+/// Estimate the translation of `b` relative to `a`, so that `b(x) ≈ a(x - shift)`.
+///
+/// The mean of each image is removed and both are tapered with a Hann window, to suppress
+/// the edge discontinuities of the implied periodic extension. See `phase_correlate_with` for other windows.
+pub fn phase_correlate<T: RealField + Copy>(a: &DMatrix<T>, b: &DMatrix<T>) -> PhaseCorrelation<T> {
+    phase_correlate_with(a, b, Window::Hann)
+}
+
+
+/// Phase correlation with a chosen window.
+///
+/// The normalized cross-power spectrum `conj(A)·B / |conj(A)·B|` is transformed back and
+/// its peak gives the integer shift. The correlation is then evaluated on a 1/20 pixel grid
+/// around that peak by a matrix DFT, and a parabola through the best grid point and its
+/// neighbours gives the final sub-pixel estimate. Shifts are reported in `(-N/2, N/2]`.
+///
+/// Panics if the images differ in shape or are empty.
+pub fn phase_correlate_with<T: RealField + Copy>(a: &DMatrix<T>, b: &DMatrix<T>, window: Window) -> PhaseCorrelation<T> {
+    assert_eq!(a.shape(), b.shape(), "images must have the same shape");
+    assert!(!a.is_empty(), "images must not be empty");
+    let (rows, cols) = a.shape();
+    let window_row = window.coefficients::<T>(rows);
+    let window_col = window.coefficients::<T>(cols);
+    // 先减去均值，否则窗函数本身的形状会在零位移处产生一个假峰
+    let taper = |m: &DMatrix<T>| {
+        let mean = m.sum() / convert((rows * cols) as f64);
+        DMatrix::from_fn(rows, cols, |i, j| Complex::new((m[(i, j)] - mean) * window_row[i] * window_col[j], T::zero()))
+    };
+
+    let fft = Fft2D::new(rows, cols);
+    let spectrum_a = fft.fft_2d(&taper(a));
+    let spectrum_b = fft.fft_2d(&taper(b));
+
+    // 归一化互功率谱
+    let cross = spectrum_a.zip_map(&spectrum_b, |x, y| {
+        let c = x.conj() * y;
+        let norm = c.modulus();
+        if norm > T::default_epsilon() {
+            c.unscale(norm)
+        } else {
+            Complex::new(T::zero(), T::zero())
+        }
+    });
+    let surface = fft.ifft_2d(&cross).map(|v| v.re);
+
+    let (mut peak_row, mut peak_col) = (0, 0);
+    for j in 0..cols {
+        for i in 0..rows {
+            if surface[(i, j)] > surface[(peak_row, peak_col)] {
+                (peak_row, peak_col) = (i, j);
+            }
+        }
+    }
+    let peak = surface[(peak_row, peak_col)];
+
+    // 超过一半的下标对应负的位移
+    let row_shift = signed_freq(peak_row, rows) * rows as f64;
+    let col_shift = signed_freq(peak_col, cols) * cols as f64;
+
+    // 在整数峰值附近用矩阵 DFT 计算上采样的相关面（Guizar-Sicairos 方法）
+    let kernel_row = upsampled_kernel::<T>(row_shift, rows);
+    let kernel_col = upsampled_kernel::<T>(col_shift, cols);
+    let scale: T = convert(1.0 / (rows * cols) as f64);
+    let fine = (&kernel_row * &cross * kernel_col.transpose()).map(|v| v.re * scale);
+
+    let (mut fine_row, mut fine_col) = (UPSAMPLE, UPSAMPLE);
+    for j in 0..fine.ncols() {
+        for i in 0..fine.nrows() {
+            if fine[(i, j)] > fine[(fine_row, fine_col)] {
+                (fine_row, fine_col) = (i, j);
+            }
+        }
+    }
+    let center = fine[(fine_row, fine_col)];
+    let last = 2 * UPSAMPLE;
+    let row_offset = if fine_row == 0 || fine_row == last {
+        T::zero()
+    } else {
+        parabolic_offset(fine[(fine_row - 1, fine_col)], center, fine[(fine_row + 1, fine_col)])
+    };
+    let col_offset = if fine_col == 0 || fine_col == last {
+        T::zero()
+    } else {
+        parabolic_offset(fine[(fine_row, fine_col - 1)], center, fine[(fine_row, fine_col + 1)])
+    };
+
+    let step: T = convert(1.0 / UPSAMPLE as f64);
+    let to_shift = |coarse: f64, index: usize, offset: T| -> T {
+        convert::<f64, T>(coarse + (index as f64 - UPSAMPLE as f64) / UPSAMPLE as f64) + offset * step
+    };
+    PhaseCorrelation {
+        row_shift: to_shift(row_shift, fine_row, row_offset),
+        col_shift: to_shift(col_shift, fine_col, col_offset),
+        peak: center.max(peak),
+    }
+}
@@ -0,0 +1,27 @@
+//! `serde` for the `DMatrix` fields of the plans without `std`: `nalgebra` only implements
+//! it for heap-allocated matrices with its `serde-serialize` feature, which needs `std`.
+//! The layout is the one `nalgebra` uses, `(data, nrows, ncols)` with the data column-major.
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, Scalar};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) fn serialize<T, S>(matrix: &DMatrix<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Scalar + Serialize,
+    S: Serializer,
+{
+    (matrix.as_slice(), matrix.nrows(), matrix.ncols()).serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<DMatrix<T>, D::Error>
+where
+    T: Scalar + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let (data, nrows, ncols): (Vec<T>, usize, usize) = Deserialize::deserialize(deserializer)?;
+    if nrows.checked_mul(ncols) != Some(data.len()) {
+        return Err(D::Error::custom("matrix data length does not match its shape"));
+    }
+    Ok(DMatrix::from_vec(nrows, ncols, data))
+}
@@ -0,0 +1,162 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::{convert, DMatrix, RealField};
+use crate::dwt::{Filters, Subbands2D};
+use crate::wavelet::Wavelet;
+
+/// Largest `level` accepted by `swt` for a signal of length `data_len`: the number of
+/// times the length can be halved exactly
+pub fn swt_max_level(data_len: usize) -> usize {
+    if data_len == 0 { 0 } else { data_len.trailing_zeros() as usize }
+}
+
+/// One level of the à-trous analysis with the filters upsampled by `step`, on a periodic signal:
+/// `a(i) = Σ_m h(m) x((i + step·(L/2 - m)) mod N)`, and likewise for the detail
+fn analysis<T: RealField + Copy>(filters: &Filters<T>, x: &[T], step: usize) -> (Vec<T>, Vec<T>) {
+    let n = x.len() as isize;
+    let half = (filters.dec_lo.len() / 2) as isize;
+    let step = step as isize;
+    let mut approx = Vec::with_capacity(x.len());
+    let mut detail = Vec::with_capacity(x.len());
+    for i in 0..n {
+        let (mut a, mut d) = (T::zero(), T::zero());
+        for (m, (&lo, &hi)) in filters.dec_lo.iter().zip(&filters.dec_hi).enumerate() {
+            let v = x[(i + step * (half - m as isize)).rem_euclid(n) as usize];
+            a += lo * v;
+            d += hi * v;
+        }
+        approx.push(a);
+        detail.push(d);
+    }
+    (approx, detail)
+}
+
+/// Inverse of `analysis`: `x((i + step·(m + 1 - L/2)) mod N) += ½ (h̃(m) a(i) + g̃(m) d(i))`.
+///
+/// The coefficients at every `step`-th position form two interleaved decimated transforms
+/// (even and odd samples); this is the average of their two periodic reconstructions.
+fn synthesis<T: RealField + Copy>(filters: &Filters<T>, approx: &[T], detail: &[T], step: usize) -> Vec<T> {
+    assert_eq!(approx.len(), detail.len(), "approximation and detail must have the same length");
+    let n = approx.len() as isize;
+    let offset = 1 - (filters.rec_lo.len() / 2) as isize;
+    let step = step as isize;
+    let half: T = convert(0.5);
+    let mut res = vec![T::zero(); approx.len()];
+    for (i, (&a, &d)) in approx.iter().zip(detail).enumerate() {
+        for (m, (&lo, &hi)) in filters.rec_lo.iter().zip(&filters.rec_hi).enumerate() {
+            let k = (i as isize + step * (m as isize + offset)).rem_euclid(n) as usize;
+            res[k] += (lo * a + hi * d) * half;
+        }
+    }
+    res
+}
+
+/// `analysis` of every column of a matrix
+fn analysis_columns<T: RealField + Copy>(filters: &Filters<T>, data: &DMatrix<T>, step: usize) -> (DMatrix<T>, DMatrix<T>) {
+    let mut lo = DMatrix::zeros(data.nrows(), data.ncols());
+    let mut hi = DMatrix::zeros(data.nrows(), data.ncols());
+    for (j, column) in data.column_iter().enumerate() {
+        let (a, d) = analysis(filters, column.as_slice(), step);
+        lo.column_mut(j).copy_from_slice(&a);
+        hi.column_mut(j).copy_from_slice(&d);
+    }
+    (lo, hi)
+}
+
+/// `synthesis` of every column pair
+fn synthesis_columns<T: RealField + Copy>(filters: &Filters<T>, lo: &DMatrix<T>, hi: &DMatrix<T>, step: usize) -> DMatrix<T> {
+    assert_eq!(lo.shape(), hi.shape(), "subbands must have the same shape");
+    let mut res = DMatrix::zeros(lo.nrows(), lo.ncols());
+    for (j, (a, d)) in lo.column_iter().zip(hi.column_iter()).enumerate() {
+        res.column_mut(j).copy_from_slice(&synthesis(filters, a.as_slice(), d.as_slice(), step));
+    }
+    res
+}
+
+
+/// Stationary (undecimated) wavelet transform of a periodic signal, by the à-trous algorithm.
+///
+/// Level `j` filters the previous approximation with the wavelet filters upsampled by
+/// `2^(j-1)` instead of downsampling, so every band keeps the signal length and the
+/// transform is shift-invariant. The signal length must be a multiple of `2^level`, as in
+/// `pywt`. Returns `(approximation, detail)` for each level, coarsest first.
+///
+/// Formula:
+/// ```latex
+/// a_j(i) = \sum_m h(m)\, a_{j-1}\big(i + 2^{j-1}(L/2 - m)\big), \quad
+/// d_j(i) = \sum_m g(m)\, a_{j-1}\big(i + 2^{j-1}(L/2 - m)\big)
+/// ```
+/// with `a_0 = x` and indices taken modulo `N`. The even samples of the first level are
+/// the periodized `Dwt` of the signal, the odd ones that of the signal shifted by one.
+pub fn swt<T: RealField + Copy>(signal: &[T], wavelet: &Wavelet, level: usize) -> Vec<(Vec<T>, Vec<T>)> {
+    assert!(level >= 1, "level must be at least 1");
+    assert!(level <= swt_max_level(signal.len()), "signal length must be a multiple of 2^level");
+    let filters = Filters::new(wavelet);
+    let mut approx = signal.to_vec();
+    let mut levels = Vec::with_capacity(level);
+    for j in 0..level {
+        let (a, d) = analysis(&filters, &approx, 1 << j);
+        levels.push((a.clone(), d));
+        approx = a;
+    }
+    levels.reverse();
+    levels
+}
+
+/// Inverse of `swt`, from the coarsest approximation and every detail band.
+///
+/// The representation is redundant by a factor of two per level; each level is inverted by
+/// averaging the reconstructions from its even and odd samples, as `pywt.iswt` does; for
+/// orthogonal wavelets that is the least-squares inverse of the level. The finer
+/// approximations are not used.
+pub fn iswt<T: RealField + Copy>(coeffs: &[(Vec<T>, Vec<T>)], wavelet: &Wavelet) -> Vec<T> {
+    assert!(!coeffs.is_empty(), "at least one level is needed");
+    let filters = Filters::new(wavelet);
+    let mut approx = coeffs[0].0.clone();
+    for (j, (_, detail)) in coeffs.iter().enumerate() {
+        approx = synthesis(&filters, &approx, detail, 1 << (coeffs.len() - 1 - j));
+    }
+    approx
+}
+
+/// 2-D stationary wavelet transform of a periodic `row × col` matrix: `swt` along the rows,
+/// then along the columns, at every level. Both sides must be multiples of `2^level`.
+///
+/// Every subband has the shape of the input, and the subbands are named as in `Subbands2D`.
+/// Returns the levels coarsest first.
+pub fn swt2<T: RealField + Copy>(data: &DMatrix<T>, wavelet: &Wavelet, level: usize) -> Vec<Subbands2D<T>> {
+    assert!(level >= 1, "level must be at least 1");
+    assert!(
+        level <= swt_max_level(data.nrows()) && level <= swt_max_level(data.ncols()),
+        "both sides must be multiples of 2^level"
+    );
+    let filters = Filters::new(wavelet);
+    let mut approx = data.clone();
+    let mut levels = Vec::with_capacity(level);
+    for j in 0..level {
+        let step = 1 << j;
+        // 行方向的滤波通过转置后按列处理
+        let (lo, hi) = analysis_columns(&filters, &approx.transpose(), step);
+        let (ll, lh) = analysis_columns(&filters, &lo.transpose(), step);
+        let (hl, hh) = analysis_columns(&filters, &hi.transpose(), step);
+        approx = ll.clone();
+        levels.push(Subbands2D { ll, lh, hl, hh });
+    }
+    levels.reverse();
+    levels
+}
+
+/// Inverse of `swt2`, from the coarsest `ll` and every level's detail subbands, averaging
+/// the redundant reconstructions along each axis as `iswt` does
+pub fn iswt2<T: RealField + Copy>(coeffs: &[Subbands2D<T>], wavelet: &Wavelet) -> DMatrix<T> {
+    assert!(!coeffs.is_empty(), "at least one level is needed");
+    let filters = Filters::new(wavelet);
+    let mut approx = coeffs[0].ll.clone();
+    for (j, subbands) in coeffs.iter().enumerate() {
+        let step = 1 << (coeffs.len() - 1 - j);
+        let lo = synthesis_columns(&filters, &approx, &subbands.lh, step);
+        let hi = synthesis_columns(&filters, &subbands.hl, &subbands.hh, step);
+        approx = synthesis_columns(&filters, &lo.transpose(), &hi.transpose(), step).transpose();
+    }
+    approx
+}
@@ -0,0 +1,511 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::{Complex, ComplexField};
+use crate::fft::expi;
+use crate::wavelet_tables::{COIFLETS, DISCRETE_MEYER, SYMLETS};
+
+/// Family a `Wavelet` belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WaveletFamily {
+    Haar,
+    Daubechies,
+    Symlets,
+    Coiflets,
+    Biorthogonal,
+    ReverseBiorthogonal,
+    DiscreteMeyer,
+    /// Built from user filters with `Wavelet::from_filters` or `Wavelet::from_lowpass`
+    Custom,
+}
+
+/// Symmetry of the filters, as reported by `pywt`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Symmetry {
+    Asymmetric,
+    NearSymmetric,
+    Symmetric,
+    Unknown,
+}
+
+/// `(Nr, Nd)` orders of the biorthogonal wavelets `biorNr.Nd` / `rbioNr.Nd`
+const BIORTHOGONAL_ORDERS: [(usize, usize); 15] = [
+    (1, 1), (1, 3), (1, 5), (2, 2), (2, 4), (2, 6), (2, 8),
+    (3, 1), (3, 3), (3, 5), (3, 7), (3, 9), (4, 4), (5, 5), (6, 8),
+];
+
+/// Tolerance of the perfect-reconstruction check in `from_filters` and `from_lowpass`
+const FILTER_TOLERANCE: f64 = 1e-8;
+
+/// Two-channel filter bank of a discrete wavelet, in the layout used by `pywt`.
+///
+/// `dec_lo`/`dec_hi` are the analysis (decomposition) filters and `rec_lo`/`rec_hi`
+/// the synthesis (reconstruction) filters. The filters are kept in `f64`; the plans
+/// convert them to their own scalar type.
+///
+/// The Daubechies and biorthogonal wavelets of the built-in catalogue (see `wavelist`) are
+/// computed at construction; symlets, coiflets and the discrete Meyer wavelet come from
+/// tables. Every wavelet can also be built by name with `from_name`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wavelet {
+    name: String,
+    family: WaveletFamily,
+    symmetry: Symmetry,
+    vanishing_moments_psi: Option<usize>,
+    vanishing_moments_phi: Option<usize>,
+    dec_lo: Vec<f64>,
+    dec_hi: Vec<f64>,
+    rec_lo: Vec<f64>,
+    rec_hi: Vec<f64>,
+}
+
+impl Wavelet {
+    /// Haar wavelet, identical to `db1`
+    pub fn haar() -> Self {
+        Self { name: "haar".to_string(), family: WaveletFamily::Haar, ..Self::daubechies(1) }
+    }
+
+    /// Daubechies wavelet `dbN` with `order = N` vanishing moments and `2N` taps, for `N` in `1..=20`.
+    ///
+    /// The filters are computed by spectral factorization rather than tabulated: the roots
+    /// of the Daubechies polynomial `Σ C(N-1+k, k) yᵏ` are mapped back to the `z`-plane and
+    /// those inside the unit circle are kept, which gives the extremal-phase filter of
+    /// `pywt`. The coefficients agree with the published tables to about `1e-12`.
+    pub fn daubechies(order: usize) -> Self {
+        assert!((1..=20).contains(&order), "Daubechies order must be in 1..=20");
+        let wavelet = Self::orthogonal(format!("db{}", order), daubechies_filter(order));
+        Self { vanishing_moments_psi: Some(order), vanishing_moments_phi: Some(0), ..wavelet }
+    }
+
+    /// Symlet `symN`, the least-asymmetric Daubechies wavelet, for `N` in `2..=20`.
+    ///
+    /// Same magnitude response as `dbN`, with a nearly linear phase. Which factorization of
+    /// the Daubechies polynomial the published filters use differs from order to order, so
+    /// the filters are tabulated; they agree with `pywt` to about `1e-12`.
+    pub fn symlet(order: usize) -> Self {
+        assert!((2..=20).contains(&order), "symlet order must be in 2..=20");
+        let dec_lo = SYMLETS[order - 2];
+        let wavelet = Self::orthogonal(format!("sym{}", order), dec_lo.iter().rev().copied().collect());
+        Self {
+            family: WaveletFamily::Symlets,
+            symmetry: Symmetry::NearSymmetric,
+            vanishing_moments_psi: Some(order),
+            vanishing_moments_phi: Some(0),
+            ..wavelet
+        }
+    }
+
+    /// Coiflet `coifN` with `6N` taps, `2N` vanishing moments of the wavelet and `2N - 1` of
+    /// the scaling function, for `N` in `1..=5`.
+    ///
+    /// The filters are the published tables of `pywt` and MATLAB. Those of `coif5` only
+    /// satisfy the defining equations to about `1e-8`, so it is orthogonal to that accuracy.
+    pub fn coiflet(order: usize) -> Self {
+        assert!((1..=5).contains(&order), "coiflet order must be in 1..=5");
+        let dec_lo = COIFLETS[order - 1];
+        let wavelet = Self::orthogonal(format!("coif{}", order), dec_lo.iter().rev().copied().collect());
+        Self {
+            family: WaveletFamily::Coiflets,
+            symmetry: Symmetry::NearSymmetric,
+            vanishing_moments_psi: Some(2 * order),
+            vanishing_moments_phi: Some(2 * order - 1),
+            ..wavelet
+        }
+    }
+
+    /// Cohen–Daubechies–Feauveau biorthogonal wavelet `biorNr.Nd`, with symmetric filters.
+    ///
+    /// `(Nr, Nd)` is one of the pairs of `pywt`: `1.1 1.3 1.5 2.2 2.4 2.6 2.8 3.1 3.3 3.5 3.7
+    /// 3.9 4.4 5.5 6.8`. The filters are zero-padded to a common even length as in `pywt`;
+    /// see `vanishing_moments_psi` for where the reported moments differ from it.
+    pub fn biorthogonal(rec_order: usize, dec_order: usize) -> Self {
+        assert!(
+            BIORTHOGONAL_ORDERS.contains(&(rec_order, dec_order)),
+            "unknown biorthogonal wavelet bior{}.{}",
+            rec_order,
+            dec_order
+        );
+        let (rec_ones, rec_lo, _, dec_lo) = biorthogonal_filters(rec_order, dec_order);
+        let dec_hi = alternate(&rec_lo).iter().map(|v| -v).collect();
+        let rec_hi = alternate(&dec_lo);
+        Self {
+            name: format!("bior{}.{}", rec_order, dec_order),
+            family: WaveletFamily::Biorthogonal,
+            symmetry: Symmetry::Symmetric,
+            // 分解小波的消失矩等于重构低通滤波器在 π 处零点的阶数
+            vanishing_moments_psi: Some(rec_ones),
+            vanishing_moments_phi: None,
+            dec_lo,
+            dec_hi,
+            rec_lo,
+            rec_hi,
+        }
+    }
+
+    /// Reverse biorthogonal wavelet `rbioNr.Nd`: `biorNr.Nd` with the roles of the analysis
+    /// and synthesis filters exchanged
+    pub fn reverse_biorthogonal(rec_order: usize, dec_order: usize) -> Self {
+        let bior = Self::biorthogonal(rec_order, dec_order);
+        let (_, _, dec_ones, _) = biorthogonal_filters(rec_order, dec_order);
+        let reversed = |h: &[f64]| h.iter().rev().copied().collect();
+        Self {
+            name: format!("rbio{}.{}", rec_order, dec_order),
+            family: WaveletFamily::ReverseBiorthogonal,
+            vanishing_moments_psi: Some(dec_ones),
+            dec_lo: reversed(&bior.rec_lo),
+            dec_hi: reversed(&bior.rec_hi),
+            rec_lo: reversed(&bior.dec_lo),
+            rec_hi: reversed(&bior.dec_hi),
+            ..bior
+        }
+    }
+
+    /// Discrete Meyer wavelet `dmey`: a 62-tap symmetric FIR approximation of the Meyer
+    /// scaling filter.
+    ///
+    /// The taps are the published ones of `pywt` and MATLAB. No symmetric FIR filter other
+    /// than Haar is exactly orthogonal, so neither is this one: `Σ h = √2` holds to rounding
+    /// error, but `Σ h² ≈ 1.002` and the perfect-reconstruction conditions only hold to
+    /// about `1e-2`.
+    pub fn discrete_meyer() -> Self {
+        let wavelet = Self::orthogonal("dmey".to_string(), DISCRETE_MEYER.iter().rev().copied().collect());
+        Self { family: WaveletFamily::DiscreteMeyer, symmetry: Symmetry::Symmetric, ..wavelet }
+    }
+
+    /// Wavelet of the built-in catalogue by its `pywt` name, such as `"haar"`, `"db4"`,
+    /// `"sym8"`, `"coif2"`, `"bior4.4"`, `"rbio2.2"` or `"dmey"`; `None` for unknown names
+    pub fn from_name(name: &str) -> Option<Self> {
+        let order = |prefix: &str| name.strip_prefix(prefix)?.parse::<usize>().ok();
+        let orders = |prefix: &str| {
+            let (r, d) = name.strip_prefix(prefix)?.split_once('.')?;
+            let pair = (r.parse::<usize>().ok()?, d.parse::<usize>().ok()?);
+            BIORTHOGONAL_ORDERS.contains(&pair).then_some(pair)
+        };
+        match name {
+            "haar" => Some(Self::haar()),
+            "dmey" => Some(Self::discrete_meyer()),
+            _ => {
+                if let Some(n) = order("db").filter(|n| (1..=20).contains(n)) {
+                    Some(Self::daubechies(n))
+                } else if let Some(n) = order("sym").filter(|n| (2..=20).contains(n)) {
+                    Some(Self::symlet(n))
+                } else if let Some(n) = order("coif").filter(|n| (1..=5).contains(n)) {
+                    Some(Self::coiflet(n))
+                } else if let Some((r, d)) = orders("bior") {
+                    Some(Self::biorthogonal(r, d))
+                } else {
+                    orders("rbio").map(|(r, d)| Self::reverse_biorthogonal(r, d))
+                }
+            }
+        }
+        // 名字必须与规范写法一致，例如不接受 "db04"
+        .filter(|wavelet| wavelet.name == name)
+    }
+
+    /// Custom wavelet from a full filter bank.
+    ///
+    /// The four filters must have the same even, non-zero length and satisfy the
+    /// perfect-reconstruction conditions (see `is_biorthogonal`) to within `1e-8`;
+    /// otherwise `None` is returned.
+    pub fn from_filters(name: &str, dec_lo: &[f64], dec_hi: &[f64], rec_lo: &[f64], rec_hi: &[f64]) -> Option<Self> {
+        let len = dec_lo.len();
+        if len == 0 || !len.is_multiple_of(2) || [dec_hi, rec_lo, rec_hi].iter().any(|h| h.len() != len) {
+            return None;
+        }
+        let wavelet = Self {
+            name: name.to_string(),
+            family: WaveletFamily::Custom,
+            symmetry: Symmetry::Unknown,
+            vanishing_moments_psi: None,
+            vanishing_moments_phi: None,
+            dec_lo: dec_lo.to_vec(),
+            dec_hi: dec_hi.to_vec(),
+            rec_lo: rec_lo.to_vec(),
+            rec_hi: rec_hi.to_vec(),
+        };
+        wavelet.is_biorthogonal(FILTER_TOLERANCE).then_some(wavelet)
+    }
+
+    /// Custom orthogonal wavelet from its decomposition low-pass filter; the other three
+    /// filters follow from the quadrature-mirror relations. `None` unless the filter has
+    /// even length and is orthogonal to its even shifts with `Σ h = √2`, to within `1e-8`
+    pub fn from_lowpass(name: &str, dec_lo: &[f64]) -> Option<Self> {
+        if dec_lo.is_empty() || !dec_lo.len().is_multiple_of(2) {
+            return None;
+        }
+        let wavelet = Self::orthogonal(name.to_string(), dec_lo.iter().rev().copied().collect());
+        let wavelet = Self { family: WaveletFamily::Custom, symmetry: Symmetry::Unknown, ..wavelet };
+        wavelet.is_orthogonal(FILTER_TOLERANCE).then_some(wavelet)
+    }
+
+    /// Orthogonal filter bank whose reconstruction low-pass filter is `rec_lo`;
+    /// the other three filters follow from the quadrature-mirror relations
+    fn orthogonal(name: String, rec_lo: Vec<f64>) -> Self {
+        let dec_lo: Vec<f64> = rec_lo.iter().rev().copied().collect();
+        let rec_hi = alternate(&dec_lo);
+        let dec_hi = rec_hi.iter().rev().copied().collect();
+        Self {
+            name,
+            family: WaveletFamily::Daubechies,
+            symmetry: Symmetry::Asymmetric,
+            vanishing_moments_psi: None,
+            vanishing_moments_phi: None,
+            dec_lo,
+            dec_hi,
+            rec_lo,
+            rec_hi,
+        }
+    }
+
+    /// Whether the filter bank reconstructs perfectly: with `L` taps,
+    /// `R_lo(z) D_lo(z) + R_hi(z) D_hi(z) = 2 z^{-(L-1)}` (no distortion) and
+    /// `R_lo(z) D_lo(-z) + R_hi(z) D_hi(-z) = 0` (no aliasing), each coefficient to within
+    /// `tolerance`
+    pub fn is_biorthogonal(&self, tolerance: f64) -> bool {
+        let len = self.filter_len();
+        let distortion = add(&convolve(&self.rec_lo, &self.dec_lo), &convolve(&self.rec_hi, &self.dec_hi));
+        let aliasing = add(
+            &convolve(&self.rec_lo, &alternate(&self.dec_lo)),
+            &convolve(&self.rec_hi, &alternate(&self.dec_hi)),
+        );
+        let target = |k: usize| if k + 1 == len { 2.0 } else { 0.0 };
+        distortion.iter().enumerate().all(|(k, v)| (v - target(k)).abs() <= tolerance)
+            && aliasing.iter().all(|v| v.abs() <= tolerance)
+    }
+
+    /// Whether the filter bank is orthogonal: biorthogonal, with each decomposition filter
+    /// the time reverse of its reconstruction filter
+    pub fn is_orthogonal(&self, tolerance: f64) -> bool {
+        let reversed = |a: &[f64], b: &[f64]| a.iter().zip(b.iter().rev()).all(|(x, y)| (x - y).abs() <= tolerance);
+        reversed(&self.dec_lo, &self.rec_lo) && reversed(&self.dec_hi, &self.rec_hi) && self.is_biorthogonal(tolerance)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn family(&self) -> WaveletFamily {
+        self.family
+    }
+
+    pub fn symmetry(&self) -> Symmetry {
+        self.symmetry
+    }
+
+    /// Vanishing moments of the decomposition wavelet: `dec_hi` annihilates polynomials of
+    /// lower degree. `None` when not defined (discrete Meyer, custom filters).
+    ///
+    /// For `biorNr.Nd` and `rbioNr.Nd` this is the count the filters actually have, which is
+    /// not always the `Nr` that `pywt` reports: `bior5.5` has 6, `rbio5.5` has 4 and
+    /// `rbioNr.Nd` otherwise has `Nd`.
+    pub fn vanishing_moments_psi(&self) -> Option<usize> {
+        self.vanishing_moments_psi
+    }
+
+    /// Vanishing moments of the scaling function beyond the zeroth, `None` when not defined
+    pub fn vanishing_moments_phi(&self) -> Option<usize> {
+        self.vanishing_moments_phi
+    }
+
+    /// Number of taps of the filters
+    pub fn filter_len(&self) -> usize {
+        self.dec_lo.len()
+    }
+
+    pub fn dec_lo(&self) -> &[f64] {
+        &self.dec_lo
+    }
+
+    pub fn dec_hi(&self) -> &[f64] {
+        &self.dec_hi
+    }
+
+    pub fn rec_lo(&self) -> &[f64] {
+        &self.rec_lo
+    }
+
+    pub fn rec_hi(&self) -> &[f64] {
+        &self.rec_hi
+    }
+}
+
+/// Names of every wavelet of the built-in catalogue, in the order of `pywt.wavelist()` families
+pub fn wavelist() -> Vec<String> {
+    let mut names = vec!["haar".to_string()];
+    names.extend((1..=20).map(|n| format!("db{}", n)));
+    names.extend((2..=20).map(|n| format!("sym{}", n)));
+    names.extend((1..=5).map(|n| format!("coif{}", n)));
+    names.extend(BIORTHOGONAL_ORDERS.iter().map(|(r, d)| format!("bior{}.{}", r, d)));
+    names.extend(BIORTHOGONAL_ORDERS.iter().map(|(r, d)| format!("rbio{}.{}", r, d)));
+    names.push("dmey".to_string());
+    names
+}
+
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    out
+}
+
+fn add(a: &[f64], b: &[f64]) -> Vec<f64> {
+    a.iter().zip(b).map(|(x, y)| x + y).collect()
+}
+
+/// Roots of the Daubechies polynomial `P(y) = Σ_{k<n} C(n-1+k, k) yᵏ`, conjugate pairs
+/// reduced to the root with positive imaginary part
+fn daubechies_roots(n: usize) -> Vec<Complex<f64>> {
+    // 系数按升幂排列
+    let mut coeffs = vec![1.0; n];
+    for k in 1..n {
+        coeffs[k] = coeffs[k - 1] * (n - 1 + k) as f64 / k as f64;
+    }
+    let mut roots: Vec<Complex<f64>> = polynomial_roots(&coeffs)
+        .into_iter()
+        .filter(|y| y.im >= -1e-12)
+        .map(|y| if y.im.abs() < 1e-12 { Complex::new(y.re, 0.0) } else { y })
+        .collect();
+    roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+    roots
+}
+
+/// For a root `y` of `P`, the root of `z² - (2 - 4y) z + 1` inside the unit circle (plus its
+/// conjugate for complex `y`), or their reciprocals when `inside` is false
+fn root_zeros(y: Complex<f64>, inside: bool) -> Vec<Complex<f64>> {
+    let b = Complex::new(1.0, 0.0) - y * 2.0;
+    let d = (b * b - 1.0).sqrt();
+    let (z_in, z_out) = if (b + d).modulus() < 1.0 { (b + d, b - d) } else { (b - d, b + d) };
+    let z = if inside { z_in } else { z_out };
+    if y.im == 0.0 { vec![z] } else { vec![z, z.conj()] }
+}
+
+/// Real filter `Π (1 + w)^ones · Π (1 - z_r w)` in `w = z⁻¹`, normalized to `Σ h = √2`
+fn filter_from_zeros(ones: usize, zeros: &[Complex<f64>]) -> Vec<f64> {
+    let mut h = vec![Complex::new(1.0, 0.0)];
+    let minus_one = [Complex::new(-1.0, 0.0)];
+    for &z in core::iter::repeat_n(&minus_one[0], ones).chain(zeros) {
+        let mut next = vec![Complex::new(0.0, 0.0); h.len() + 1];
+        for (i, &c) in h.iter().enumerate() {
+            next[i] += c;
+            next[i + 1] -= c * z;
+        }
+        h = next;
+    }
+    // 共轭根成对出现，结果是实数
+    let sum: f64 = h.iter().map(|c| c.re).sum();
+    let scale = core::f64::consts::SQRT_2 / sum;
+    h.iter().map(|c| c.re * scale).collect()
+}
+
+/// Minimum-phase Daubechies low-pass filter of order `n`.
+///
+/// `|H(ω)|² = cos^{2N}(ω/2) P(sin²(ω/2))`; with `y = (2 - z - z⁻¹)/4` every root of `P`
+/// gives a pair `z, 1/z`, of which the one inside the unit circle is a zero of `H`.
+fn daubechies_filter(n: usize) -> Vec<f64> {
+    let zeros: Vec<Complex<f64>> = daubechies_roots(n).into_iter().flat_map(|y| root_zeros(y, true)).collect();
+    filter_from_zeros(n, &zeros)
+}
+
+/// All complex roots of the real polynomial `Σ coeffs[k] xᵏ`, by the Aberth–Ehrlich iteration
+pub(crate) fn polynomial_roots(coeffs: &[f64]) -> Vec<Complex<f64>> {
+    let degree = coeffs.len().saturating_sub(1);
+    if degree == 0 {
+        return Vec::new();
+    }
+    let lead = coeffs[degree];
+    let monic: Vec<f64> = coeffs.iter().map(|c| c / lead).collect();
+
+    // 初值放在 Fujiwara 上界 2·max|a_k|^{1/(n-k)} 的圆上，角度错开以打破对称
+    let radius = monic[..degree]
+        .iter()
+        .enumerate()
+        .map(|(k, c)| c.abs().powf(1.0 / (degree - k) as f64))
+        .fold(0.0, f64::max)
+        * 2.0;
+    let mut roots: Vec<Complex<f64>> = (0..degree)
+        .map(|k| expi::<f64>(2.0 * core::f64::consts::PI * (k as f64 + 0.25) / degree as f64 + 0.4) * radius)
+        .collect();
+
+    let eval = |x: Complex<f64>| {
+        // Horner 法同时计算 p(x) 和 p'(x)
+        let mut p = Complex::new(monic[degree], 0.0);
+        let mut dp = Complex::new(0.0, 0.0);
+        for &c in monic[..degree].iter().rev() {
+            dp = dp * x + p;
+            p = p * x + c;
+        }
+        (p, dp)
+    };
+    for _ in 0..500 {
+        let mut largest_step: f64 = 0.0;
+        for i in 0..degree {
+            let (p, dp) = eval(roots[i]);
+            if p.modulus() == 0.0 {
+                continue;
+            }
+            let ratio = p / dp;
+            let repulsion = (0..degree)
+                .filter(|&j| j != i)
+                .fold(Complex::new(0.0, 0.0), |acc, j| acc + (roots[i] - roots[j]).inv());
+            let step = ratio / (Complex::new(1.0, 0.0) - ratio * repulsion);
+            roots[i] -= step;
+            largest_step = largest_step.max(step.modulus() / roots[i].modulus().max(1.0));
+        }
+        if largest_step < 1e-16 {
+            break;
+        }
+    }
+    roots
+}
+
+/// Filters of `biorNr.Nd`: `(rec_ones, rec_lo, dec_ones, dec_lo)`, where `*_ones` is the
+/// multiplicity of the zero at `z = -1`, zero-padded to the common even length of `pywt`.
+///
+/// Both low-pass filters are `(1 + z⁻¹)^ones` times a share of the factors `y - y_r` of the
+/// Daubechies polynomial of order `(Nr + Nd) / 2`, with `y = (2 - z - z⁻¹)/4`; each factor is
+/// symmetric, so so are the filters. The spline wavelets give all factors to the
+/// decomposition filter, the others split them as in Cohen–Daubechies–Feauveau.
+fn biorthogonal_filters(rec_order: usize, dec_order: usize) -> (usize, Vec<f64>, usize, Vec<f64>) {
+    let roots = daubechies_roots((rec_order + dec_order) / 2);
+    let (rec_ones, rec_roots, dec_ones, dec_roots) = match (rec_order, dec_order) {
+        (4, 4) => {
+            let (real, complex) = roots.iter().partition(|y| y.im == 0.0);
+            (4, real, 4, complex)
+        }
+        (5, 5) => (6, vec![roots[0]], 4, vec![roots[1]]),
+        (6, 8) => (6, vec![roots[1]], 8, vec![roots[0], roots[2]]),
+        _ => (rec_order, Vec::new(), dec_order, roots),
+    };
+    let factor = |ones: usize, roots: &[Complex<f64>]| {
+        // y - y_r 的两个 z 平面零点互为倒数
+        let zeros: Vec<Complex<f64>> = roots
+            .iter()
+            .flat_map(|&y| root_zeros(y, true).into_iter().chain(root_zeros(y, false)))
+            .collect();
+        filter_from_zeros(ones, &zeros)
+    };
+    let rec = factor(rec_ones, &rec_roots);
+    let dec = factor(dec_ones, &dec_roots);
+
+    // pywt 的布局：奇数长的滤波器分解端居中于 L/2，重构端居中于 L/2 - 1，中心之和为 L - 1
+    let len = rec.len().max(dec.len()).next_multiple_of(2);
+    let place = |h: &[f64], centre: usize| {
+        let offset = if h.len() % 2 == 1 { centre - h.len() / 2 } else { (len - h.len()) / 2 };
+        let mut padded = vec![0.0; len];
+        padded[offset..offset + h.len()].copy_from_slice(h);
+        padded
+    };
+    (rec_ones, place(&rec, len / 2 - 1), dec_ones, place(&dec, len / 2))
+}
+
+/// `(-1)ᵏ h(k)`
+fn alternate(h: &[f64]) -> Vec<f64> {
+    h.iter().enumerate().map(|(k, &v)| if k % 2 == 0 { v } else { -v }).collect()
+}
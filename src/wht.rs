@@ -0,0 +1,229 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+use nalgebra::{convert, DMatrix, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Order in which the Walsh functions appear in the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhtOrdering {
+    /// Hadamard order, the rows of the Sylvester matrix `H_N = H_2 ⊗ H_{N/2}`
+    Natural,
+    /// Walsh order, by increasing number of sign changes
+    Sequency,
+    /// Paley order, the bit-reversal of natural order
+    Dyadic,
+}
+
+impl WhtOrdering {
+    /// `perm[s]` is the natural-order index of the `s`-th output
+    fn permutation(self, size: usize) -> Vec<usize> {
+        let bits = size.trailing_zeros();
+        let reverse = |i: usize| if bits == 0 { 0 } else { i.reverse_bits() >> (usize::BITS - bits) };
+        (0..size)
+            .map(|s| match self {
+                WhtOrdering::Natural => s,
+                // 格雷码再位反转
+                WhtOrdering::Sequency => reverse(s ^ (s >> 1)),
+                WhtOrdering::Dyadic => reverse(s),
+            })
+            .collect()
+    }
+}
+
+
+/// Unnormalized butterflies `(a, b) → (a + b, a - b)`, output in natural order
+fn butterflies<V: Copy + Add<Output = V> + Sub<Output = V>>(data: &mut [V]) {
+    let mut half = 1;
+    while half < data.len() {
+        for chunk in data.chunks_exact_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        }
+        half <<= 1;
+    }
+}
+
+
+/// Fast Walsh–Hadamard transform plan for power-of-two lengths.
+///
+/// Uses `N log₂ N` additions and subtractions and no multiplications except
+/// the final `1/√N`, which makes the transform orthonormal.
+/// In `Natural` ordering it is its own inverse.
+///
+/// Formula:
+/// ```latex
+/// X(k) = \frac{1}{\sqrt{N}} \sum_{n=0}^{N-1} x(n) (-1)^{\sum_i k_i n_i}
+/// ```
+/// where `k_i`, `n_i` are the bits of `k` and `n` (natural ordering).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wht<T: RealField = f32> {
+    ordering: WhtOrdering,
+    permutation: Vec<usize>,
+    scale: T,
+    size: usize,
+}
+
+impl<T: RealField + Copy> Wht<T> {
+    pub fn new(size: usize, ordering: WhtOrdering) -> Self {
+        assert!(size.is_power_of_two(), "size must be a power of two");
+        Self {
+            ordering,
+            permutation: ordering.permutation(size),
+            scale: convert(1.0 / (size as f64).sqrt()),
+            size,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn ordering(&self) -> WhtOrdering {
+        self.ordering
+    }
+
+
+    pub fn wht(&self, data: &[T]) -> Vec<T> {
+        let mut res = data.to_vec();
+        self.wht_inplace(&mut res);
+        res
+    }
+
+    pub fn iwht(&self, data: &[T]) -> Vec<T> {
+        let mut res = data.to_vec();
+        self.iwht_inplace(&mut res);
+        res
+    }
+
+    pub fn wht_inplace(&self, data: &mut [T]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        butterflies(data);
+        self.natural_to_ordered(data);
+        data.iter_mut().for_each(|v| *v *= self.scale);
+    }
+
+    pub fn iwht_inplace(&self, data: &mut [T]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        self.ordered_to_natural(data);
+        butterflies(data);
+        data.iter_mut().for_each(|v| *v *= self.scale);
+    }
+
+
+    /// Integer WHT without scaling, so it is exact: the outputs are sums of `±x(n)`
+    /// and grow by at most a factor of `N`
+    pub fn wht_int_inplace(&self, data: &mut [i64]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        butterflies(data);
+        self.natural_to_ordered(data);
+    }
+
+    /// Exact inverse of `wht_int_inplace`: the butterflies again, then an exact division by `N`
+    pub fn iwht_int_inplace(&self, data: &mut [i64]) {
+        assert_eq!(data.len(), self.size, "input length must equal the plan size");
+        self.ordered_to_natural(data);
+        butterflies(data);
+        let n = self.size as i64;
+        data.iter_mut().for_each(|v| {
+            debug_assert!(*v % n == 0, "input is not the WHT of an integer signal");
+            *v /= n;
+        });
+    }
+
+    /// Natural order → the plan's order
+    fn natural_to_ordered<V: Copy>(&self, data: &mut [V]) {
+        if self.ordering != WhtOrdering::Natural {
+            let natural = data.to_vec();
+            for (v, &k) in data.iter_mut().zip(&self.permutation) {
+                *v = natural[k];
+            }
+        }
+    }
+
+    /// The plan's order → natural order
+    fn ordered_to_natural<V: Copy>(&self, data: &mut [V]) {
+        if self.ordering != WhtOrdering::Natural {
+            let ordered = data.to_vec();
+            for (&v, &k) in ordered.iter().zip(&self.permutation) {
+                data[k] = v;
+            }
+        }
+    }
+}
+
+
+/// Two-dimensional Walsh–Hadamard transform plan for `row × col` matrices,
+/// applied along the rows and then the columns with the same ordering
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wht2D<T: RealField = f32> {
+    wht_row: Wht<T>,
+    wht_col: Wht<T>,
+}
+
+impl<T: RealField + Copy> Wht2D<T> {
+    pub fn new(row: usize, col: usize, ordering: WhtOrdering) -> Self {
+        Self { wht_row: Wht::new(col, ordering), wht_col: Wht::new(row, ordering) }
+    }
+
+    pub fn wht_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        let mut res = data.clone();
+        self.wht_2d_inplace(&mut res);
+        res
+    }
+
+    pub fn iwht_2d(&self, data: &DMatrix<T>) -> DMatrix<T> {
+        let mut res = data.clone();
+        self.iwht_2d_inplace(&mut res);
+        res
+    }
+
+    pub fn wht_2d_inplace(&self, data: &mut DMatrix<T>) {
+        self.process(data, Wht::wht_inplace);
+    }
+
+    pub fn iwht_2d_inplace(&self, data: &mut DMatrix<T>) {
+        self.process(data, Wht::iwht_inplace);
+    }
+
+    /// Integer 2-D WHT, see `Wht::wht_int_inplace`
+    pub fn wht_2d_int_inplace(&self, data: &mut DMatrix<i64>) {
+        self.process(data, Wht::wht_int_inplace);
+    }
+
+    /// Exact inverse of `wht_2d_int_inplace`
+    pub fn iwht_2d_int_inplace(&self, data: &mut DMatrix<i64>) {
+        self.process(data, Wht::iwht_int_inplace);
+    }
+
+    fn process<V>(&self, data: &mut DMatrix<V>, transform: fn(&Wht<T>, &mut [V]))
+    where
+        V: nalgebra::Scalar + Copy,
+    {
+        assert_eq!(data.shape(), (self.wht_col.len(), self.wht_row.len()), "input shape must equal the plan shape");
+        // 行在 DMatrix 中不连续，复制出来再变换
+        let mut buf = Vec::with_capacity(data.ncols());
+        for i in 0..data.nrows() {
+            buf.clear();
+            buf.extend(data.row(i).iter().copied());
+            transform(&self.wht_row, &mut buf);
+            for (j, &v) in buf.iter().enumerate() {
+                data[(i, j)] = v;
+            }
+        }
+        for mut column in data.column_iter_mut() {
+            transform(&self.wht_col, column.as_mut_slice());
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use nalgebra::{convert, RealField};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Window functions for tapering a signal before a transform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl Window {
+    /// Symmetric window of length `n`, i.e. the first and last samples are equal
+    pub fn coefficients<T: RealField + Copy>(&self, n: usize) -> Vec<T> {
+        if n == 1 {
+            return alloc::vec![T::one()];
+        }
+        (0..n)
+            .map(|i| {
+                let x = 2.0 * PI * i as f64 / (n - 1) as f64;
+                let w = match self {
+                    Window::Rectangular => 1.0,
+                    Window::Hann => 0.5 - 0.5 * x.cos(),
+                    Window::Hamming => 0.54 - 0.46 * x.cos(),
+                    Window::Blackman => 0.42 - 0.5 * x.cos() + 0.08 * (2.0 * x).cos(),
+                };
+                convert(w)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests_complex {
+    use signal_transforms::dct::{Dct, Dct2D};
+    use signal_transforms::dst::{Dst, Dst2D};
+    use nalgebra::{Complex, DMatrix};
+    use rand::Rng;
+
+    fn random_complex(rows: usize, cols: usize) -> DMatrix<Complex<f64>> {
+        let mut rng = rand::thread_rng();
+        DMatrix::from_fn(rows, cols, |_, _| Complex::new(rng.gen_range(-255.0..=255.0), rng.gen_range(-255.0..=255.0)))
+    }
+
+    fn assert_complex_close(a: &DMatrix<Complex<f64>>, b: &DMatrix<Complex<f64>>, epsilon: f64) {
+        assert_eq!(a.shape(), b.shape());
+        let max_diff = a.iter().zip(b.iter()).map(|(x, y)| (x - y).norm()).fold(0.0, f64::max);
+        assert!(max_diff < epsilon, "max difference = {}", max_diff);
+    }
+
+    fn re(m: &DMatrix<Complex<f64>>) -> DMatrix<f64> {
+        m.map(|v| v.re)
+    }
+
+    fn im(m: &DMatrix<Complex<f64>>) -> DMatrix<f64> {
+        m.map(|v| v.im)
+    }
+
+    fn join(re: &DMatrix<f64>, im: &DMatrix<f64>) -> DMatrix<Complex<f64>> {
+        re.zip_map(im, Complex::new)
+    }
+
+    #[test]
+    fn test_dct_1d_complex() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let size = rng.gen_range(1..=32);
+            let dct = Dct::<f64>::new(size);
+            let input = random_complex(1, size);
+
+            // 与分别变换实部、虚部的结果一致
+            let res = dct.dct_1d_complex(&input);
+            assert_complex_close(&res, &join(&dct.dct_1d(&re(&input)), &dct.dct_1d(&im(&input))), 1e-9);
+            assert_complex_close(&dct.idct_1d_complex(&res), &input, 1e-9);
+
+            // 多行输入，每行各自变换
+            let rows = random_complex(3, size);
+            let res = dct.dct_1d_complex(&rows);
+            for i in 0..3 {
+                assert_complex_close(&res.rows(i, 1).into_owned(), &dct.dct_1d_complex(&rows.rows(i, 1).into_owned()), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dct_2d_complex() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let rows = rng.gen_range(1..=32);
+            let cols = rng.gen_range(1..=32);
+            let dct = Dct2D::<f64>::new(rows, cols);
+            let input = random_complex(rows, cols);
+
+            let res = dct.dct_2d_complex(&input);
+            assert_complex_close(&res, &join(&dct.dct_2d(&re(&input)), &dct.dct_2d(&im(&input))), 1e-9);
+            assert_complex_close(&dct.idct_2d_complex(&res), &input, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dst_complex() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let rows = rng.gen_range(1..=32);
+            let cols = rng.gen_range(1..=32);
+
+            let dst = Dst::<f64>::new(cols);
+            let input = random_complex(1, cols);
+            let res = dst.dst_1d_complex(&input);
+            assert_complex_close(&res, &join(&dst.dst_1d(&re(&input)), &dst.dst_1d(&im(&input))), 1e-9);
+            assert_complex_close(&dst.idst_1d_complex(&res), &input, 1e-9);
+
+            let dst = Dst2D::<f64>::new(rows, cols);
+            let input = random_complex(rows, cols);
+            let res = dst.dst_2d_complex(&input);
+            assert_complex_close(&res, &join(&dst.dst_2d(&re(&input)), &dst.dst_2d(&im(&input))), 1e-9);
+            assert_complex_close(&dst.idst_2d_complex(&res), &input, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dct_complex_f32() {
+        let dct = Dct2D::new(4, 6);
+        let input = DMatrix::from_fn(4, 6, |i, j| Complex::new((i * 6 + j) as f32, (i + j) as f32 - 3.0));
+        let back = dct.idct_2d_complex(&dct.dct_2d_complex(&input));
+        let max_diff = input.iter().zip(back.iter()).map(|(x, y)| (x - y).norm()).fold(0.0, f32::max);
+        assert!(max_diff < 1e-3, "max difference = {}", max_diff);
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests_convolve {
+    use signal_transforms::convolve::{convolve, convolve_2d, correlate, correlate_2d, ConvolveMode, OverlapAdd, OverlapSave};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    fn random_signal(size: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect()
+    }
+
+    fn direct(a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut res = vec![0.0; a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                res[i + j] += x * y;
+            }
+        }
+        res
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        assert!(a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-9), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_convolve_modes() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [0.0, 1.0, 0.5];
+        assert_close(&convolve(&a, &b, ConvolveMode::Full), &[0.0, 1.0, 2.5, 4.0, 1.5]);
+        assert_close(&convolve(&a, &b, ConvolveMode::Same), &[1.0, 2.5, 4.0]);
+        assert_close(&convolve(&a, &b, ConvolveMode::Valid), &[2.5]);
+        assert_close(&correlate(&a, &b, ConvolveMode::Full), &[0.5, 2.0, 3.5, 3.0, 0.0]);
+        assert!(convolve::<f64>(&[], &b, ConvolveMode::Full).is_empty());
+    }
+
+    #[test]
+    fn test_convolve_random() {
+        for (n, m) in [(1, 1), (17, 5), (100, 33), (8, 64), (1000, 257)] {
+            let a = random_signal(n);
+            let b = random_signal(m);
+            let full = direct(&a, &b);
+            assert_close(&convolve(&a, &b, ConvolveMode::Full), &full);
+            let start = (m - 1) / 2;
+            assert_close(&convolve(&a, &b, ConvolveMode::Same), &full[start..start + n]);
+            let lo = n.min(m) - 1;
+            assert_close(&convolve(&a, &b, ConvolveMode::Valid), &full[lo..=n.max(m) - 1]);
+        }
+    }
+
+    #[test]
+    fn test_convolve_2d() {
+        let mut rng = rand::thread_rng();
+        for ((r1, c1), (r2, c2)) in [((5, 7), (3, 2)), ((16, 9), (4, 4)), ((3, 3), (6, 5))] {
+            let a = DMatrix::from_fn(r1, c1, |_, _| rng.gen_range(-1.0..=1.0));
+            let b = DMatrix::from_fn(r2, c2, |_, _| rng.gen_range(-1.0..=1.0));
+            let mut full = DMatrix::zeros(r1 + r2 - 1, c1 + c2 - 1);
+            for i in 0..r1 {
+                for j in 0..c1 {
+                    for k in 0..r2 {
+                        for l in 0..c2 {
+                            full[(i + k, j + l)] += a[(i, j)] * b[(k, l)];
+                        }
+                    }
+                }
+            }
+            assert!((convolve_2d(&a, &b, ConvolveMode::Full) - &full).amax() < 1e-9);
+            let same = convolve_2d(&a, &b, ConvolveMode::Same);
+            assert_eq!(same.shape(), (r1, c1));
+            assert!((same - full.view(((r2 - 1) / 2, (c2 - 1) / 2), (r1, c1))).amax() < 1e-9);
+            let valid = convolve_2d(&a, &b, ConvolveMode::Valid);
+            assert_eq!(valid.shape(), (r1.max(r2) - r1.min(r2) + 1, c1.max(c2) - c1.min(c2) + 1));
+
+            // 相关等于与翻转后的核做卷积
+            let flipped = DMatrix::from_fn(r2, c2, |i, j| b[(r2 - 1 - i, c2 - 1 - j)]);
+            let corr = correlate_2d(&a, &b, ConvolveMode::Full);
+            assert!((corr - convolve_2d(&a, &flipped, ConvolveMode::Full)).amax() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let mut rng = rand::thread_rng();
+        let kernel = random_signal(300);
+        let signal = random_signal(5000);
+        let expected = direct(&signal, &kernel);
+        for block_size in [1, 64, 500] {
+            let mut ola = OverlapAdd::new(&kernel, block_size);
+            let mut ols = OverlapSave::new(&kernel, block_size);
+            assert_eq!(ola.latency(), block_size);
+            let (mut out_a, mut out_s) = (Vec::new(), Vec::new());
+            let mut pos = 0;
+            while pos < signal.len() {
+                // 每次送入任意长度的输入，输出长度与输入相同
+                let len = rng.gen_range(0..=700).min(signal.len() - pos);
+                let chunk = &signal[pos..pos + len];
+                let (a, s) = (ola.process(chunk), ols.process(chunk));
+                assert_eq!((a.len(), s.len()), (len, len));
+                out_a.extend(a);
+                out_s.extend(s);
+                pos += len;
+            }
+            let latency = ola.latency();
+            assert!(out_a[..latency].iter().all(|&v| v == 0.0));
+            assert_close(&out_a[latency..], &expected[..signal.len() - latency]);
+            assert_close(&out_s[latency..], &expected[..signal.len() - latency]);
+
+            ola.reset();
+            ols.reset();
+            let impulse: Vec<f64> = (0..block_size + kernel.len()).map(|i| if i == 0 { 1.0 } else { 0.0 }).collect();
+            assert_close(&ola.process(&impulse)[latency..], &kernel[..impulse.len() - latency]);
+            assert_close(&ols.process(&impulse)[latency..], &kernel[..impulse.len() - latency]);
+        }
+    }
+}
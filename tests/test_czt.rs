@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests_czt {
+    use signal_transforms::czt::{zoom_fft, Czt};
+    use signal_transforms::fft::Fft;
+    use nalgebra::Complex;
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn random_signal(size: usize) -> Vec<Complex<f64>> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| Complex::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0))).collect()
+    }
+
+    fn max_diff(a: &[Complex<f64>], b: &[Complex<f64>]) -> f64 {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).map(|(x, y)| (x - y).norm()).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_czt_equals_fft() {
+        for n in [1, 2, 7, 16, 30] {
+            let w = Complex::from_polar(1.0, -2.0 * PI / n as f64);
+            let czt = Czt::new(n, n, w, Complex::new(1.0, 0.0));
+            let input = random_signal(n);
+            assert!(max_diff(&czt.czt(&input), &Fft::new(n).fft(&input)) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_czt_spiral() {
+        let (n, m) = (13, 21);
+        let w = Complex::from_polar(1.01, -0.17);
+        let a = Complex::from_polar(0.95, 0.4);
+        let czt = Czt::new(n, m, w, a);
+        let input = random_signal(n);
+
+        // X(k) = Σ x(n) z_k^{-n}
+        let points = czt.points();
+        let expected: Vec<Complex<f64>> = points.iter().map(|z| {
+            input.iter().enumerate().map(|(i, x)| x * z.powi(-(i as i32))).sum()
+        }).collect();
+        assert!(max_diff(&czt.czt(&input), &expected) < 1e-8);
+        assert!((points[0] - a).norm() < 1e-12);
+        assert!((points[1] - a / w).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_zoom_fft() {
+        let fs = 1000.0;
+        let n = 200;
+        // 两个相距很近的正弦
+        let signal: Vec<Complex<f64>> = (0..n).map(|i| {
+            let t = i as f64 / fs;
+            Complex::new((2.0 * PI * 100.0 * t).sin() + 0.5 * (2.0 * PI * 103.0 * t).sin(), 0.0)
+        }).collect();
+
+        let (f_start, f_end, m) = (95.0, 110.0, 61);
+        let res = zoom_fft(&signal, f_start, f_end, m, fs);
+        assert_eq!(res.len(), m);
+        for (k, v) in res.iter().enumerate() {
+            let f = f_start + (f_end - f_start) * k as f64 / (m - 1) as f64;
+            let expected: Complex<f64> = signal.iter().enumerate().map(|(i, x)| {
+                x * Complex::from_polar(1.0, -2.0 * PI * f * i as f64 / fs)
+            }).sum();
+            assert!((v - expected).norm() < 1e-8, "f = {}", f);
+        }
+
+        let plan = Czt::zoom(n, f_start, f_end, m, fs);
+        assert!(max_diff(&plan.czt(&signal), &res) < 1e-12);
+    }
+}
@@ -41,5 +41,62 @@ mod tests_dct_raw {
             println!("{:?}", row);
         }
     }
-}
 
+    use signal_transforms::dct_raw_algo::{accuracy_report, accuracy_report_2d, dst_2d, idst_2d, TransformKind};
+    use signal_transforms::dct::{Dct, Dct2D};
+    use signal_transforms::dst::Dst;
+    use nalgebra::DMatrix;
+
+    fn max_diff(a: &[Vec<f64>], b: &[Vec<f64>]) -> f64 {
+        a.iter().flatten().zip(b.iter().flatten()).map(|(x, y)| (x - y).abs()).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn tst_rectangular_2d() {
+        let matrix = vec![
+            vec![52.0, 55.0, 61.0, 66.0, 70.0],
+            vec![70.0, 61.0, 64.0, 73.0, 63.0],
+            vec![63.0, 59.0, 55.0, 90.0, 67.0],
+        ];
+        assert!(max_diff(&idct_2d(&dct_2d(&matrix)), &matrix) < 1e-9);
+        assert!(max_diff(&idst_2d(&dst_2d(&matrix)), &matrix) < 1e-9);
+
+        // 与快速实现一致
+        let fast = Dct2D::<f64>::new(3, 5).dct_2d(&DMatrix::from_fn(3, 5, |i, j| matrix[i][j]));
+        let fast: Vec<Vec<f64>> = (0..3).map(|i| (0..5).map(|j| fast[(i, j)]).collect()).collect();
+        assert!(max_diff(&dct_2d(&matrix), &fast) < 1e-9);
+    }
+
+    #[test]
+    fn tst_accuracy_report() {
+        let sizes: Vec<usize> = (1..=24).collect();
+        let report = accuracy_report(TransformKind::Dct, &sizes, |size, input| {
+            Dct::<f64>::new(size).dct_1d(&DMatrix::from_row_slice(1, size, input)).as_slice().to_vec()
+        });
+        assert_eq!(report.len(), sizes.len());
+        for r in &report {
+            assert!(r.max_error < 1e-9 && r.rms_error <= r.max_error, "{:?}", r);
+        }
+
+        // f32 的快速实现误差更大，但仍然有界
+        let report = accuracy_report(TransformKind::Idst, &sizes, |size, input| {
+            let input: Vec<f32> = input.iter().map(|&v| v as f32).collect();
+            let res = Dst::new(size).idst_1d(&DMatrix::from_row_slice(1, size, &input));
+            res.iter().map(|&v| v as f64).collect()
+        });
+        for r in &report {
+            assert!(r.max_error < 1e-4, "{:?}", r);
+        }
+
+        let shapes = [(1, 1), (2, 7), (8, 8), (13, 4)];
+        let report = accuracy_report_2d(TransformKind::Idct, &shapes, |input| {
+            let (rows, cols) = (input.len(), input[0].len());
+            let res = Dct2D::<f64>::new(rows, cols).idct_2d(&DMatrix::from_fn(rows, cols, |i, j| input[i][j]));
+            (0..rows).map(|i| (0..cols).map(|j| res[(i, j)]).collect()).collect()
+        });
+        for (r, &(rows, cols)) in report.iter().zip(shapes.iter()) {
+            assert_eq!((r.rows, r.cols), (rows, cols));
+            assert!(r.max_error < 1e-9, "{:?}", r);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests_dht {
+    use signal_transforms::dht::{Dht, Dht2D};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn cas(x: f64) -> f64 {
+        x.cos() + x.sin()
+    }
+
+    fn random_matrix(rows: usize, cols: usize) -> DMatrix<f64> {
+        let mut rng = rand::thread_rng();
+        DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-1.0..=1.0))
+    }
+
+    #[test]
+    fn test_dht_1d() {
+        for n in [1, 2, 5, 8, 15, 64] {
+            let dht = Dht::<f64>::new(n);
+            let input = random_matrix(3, n);
+            let res = dht.dht_1d(&input);
+            for i in 0..3 {
+                for k in 0..n {
+                    let expected: f64 = (0..n).map(|x| input[(i, x)] * cas(2.0 * PI * (x * k) as f64 / n as f64)).sum::<f64>()
+                        / (n as f64).sqrt();
+                    assert!((res[(i, k)] - expected).abs() < 1e-9);
+                }
+            }
+            // 自逆
+            assert!((dht.idht_1d(&res) - &input).amax() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dht_2d() {
+        for (rows, cols) in [(1, 1), (4, 6), (7, 3), (8, 8)] {
+            let dht = Dht2D::<f64>::new(rows, cols);
+            let input = random_matrix(rows, cols);
+            let separable = dht.dht_2d_separable(&input);
+            let full = dht.dht_2d(&input);
+            let scale = ((rows * cols) as f64).sqrt();
+            for u in 0..rows {
+                for v in 0..cols {
+                    let (mut expected_sep, mut expected) = (0.0, 0.0);
+                    for x in 0..rows {
+                        for y in 0..cols {
+                            let a = 2.0 * PI * (u * x) as f64 / rows as f64;
+                            let b = 2.0 * PI * (v * y) as f64 / cols as f64;
+                            expected_sep += input[(x, y)] * cas(a) * cas(b);
+                            expected += input[(x, y)] * cas(a + b);
+                        }
+                    }
+                    assert!((separable[(u, v)] - expected_sep / scale).abs() < 1e-9);
+                    assert!((full[(u, v)] - expected / scale).abs() < 1e-9);
+                }
+            }
+            assert!((dht.idht_2d_separable(&separable) - &input).amax() < 1e-12);
+            assert!((dht.idht_2d(&full) - &input).amax() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dht_f32() {
+        let dht = Dht::<f32>::new(16);
+        let input = DMatrix::from_fn(1, 16, |_, j| (j as f32 * 0.7).sin());
+        assert!((dht.dht_1d(&dht.dht_1d(&input)) - &input).amax() < 1e-5);
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests_dst {
+    use signal_transforms::dst::{Dst, Dst2D};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    fn generate_random_2d(rows: usize, cols: usize) -> Vec<f32> {
+        let mut rng = rand::thread_rng();
+        (0..rows * cols).map(|_| rng.gen_range(0.0..=255.0)).collect()
+    }
+
+    fn assert_matrices_close(a: &DMatrix<f32>, b: &DMatrix<f32>, epsilon: f32) {
+        let max_diff = (a - b).abs().max();
+        assert!(max_diff < epsilon, "max difference = {}", max_diff);
+    }
+
+    const EPSILON: f32 = 1e-2;
+
+    #[test]
+    fn test_dst_1d_formula() {
+        // DST-II 直接按定义计算
+        let input = vec![52.0, 55.0, 61.0, 66.0, 70.0];
+        let n = input.len();
+        let expected: Vec<f32> = (0..n).map(|u| {
+            let beta = if u == n - 1 { (1.0 / n as f32).sqrt() } else { (2.0 / n as f32).sqrt() };
+            beta * (0..n).map(|x| {
+                input[x] * (std::f32::consts::PI * (x as f32 + 0.5) * (u + 1) as f32 / n as f32).sin()
+            }).sum::<f32>()
+        }).collect();
+
+        let dst = Dst::new(n);
+        let res = dst.dst_1d(&DMatrix::from_row_slice(1, n, &input));
+        assert_matrices_close(&res, &DMatrix::from_row_slice(1, n, &expected), EPSILON);
+    }
+
+    #[test]
+    fn test_dst_idst_1d_random() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let size = rng.gen_range(1..=32);
+            let dst = Dst::new(size);
+            let input = DMatrix::from_row_slice(1, size, &generate_random_2d(1, size));
+            let res = dst.idst_1d(&dst.dst_1d(&input));
+            assert_matrices_close(&input, &res, EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_dst_2d_random() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let rows = rng.gen_range(1..=32);
+            let cols = rng.gen_range(1..=32);
+            let dst2d = Dst2D::new(rows, cols);
+            let input = DMatrix::from_row_slice(rows, cols, &generate_random_2d(rows, cols));
+            let res = dst2d.idst_2d(&dst2d.dst_2d(&input));
+            assert_matrices_close(&input, &res, EPSILON);
+        }
+    }
+}
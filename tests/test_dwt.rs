@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests_dwt {
+    use signal_transforms::dwt::{dwt_max_level, wavedec, wavedec2, waverec, waverec2, Dwt, Dwt2D, ExtensionMode};
+    use nalgebra::DMatrix;
+    use signal_transforms::wavelet::Wavelet;
+    use rand::Rng;
+
+    fn random_signal(size: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect()
+    }
+
+    fn energy(x: &[f64]) -> f64 {
+        x.iter().map(|v| v * v).sum()
+    }
+
+    #[test]
+    fn test_dwt_haar() {
+        let dwt = Dwt::<f64>::new(4, &Wavelet::haar());
+        let (a, d) = dwt.dwt(&[1.0, 2.0, 3.0, 4.0]);
+        let s = core::f64::consts::FRAC_1_SQRT_2;
+        assert!((a[0] - 3.0 * s).abs() < 1e-12 && (a[1] - 7.0 * s).abs() < 1e-12);
+        assert!((d[0] + s).abs() < 1e-12 && (d[1] + s).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dwt_reconstruction() {
+        for order in [1, 2, 3, 4, 6, 10, 20] {
+            let wavelet = Wavelet::daubechies(order);
+            for size in [1, 2, 7, 16, 33, 64, 101] {
+                let dwt = Dwt::<f64>::new(size, &wavelet);
+                let x = random_signal(size);
+                let (a, d) = dwt.dwt(&x);
+                assert_eq!(a.len(), dwt.coefficient_len());
+                assert_eq!(d.len(), dwt.coefficient_len());
+                let y = dwt.idwt(&a, &d);
+                assert_eq!(y.len(), size);
+                for (u, v) in x.iter().zip(&y) {
+                    assert!((u - v).abs() < 1e-9, "db{} size={}", order, size);
+                }
+                // 偶数长度时，正交小波的周期化变换保持能量
+                if size % 2 == 0 {
+                    assert!((energy(&a) + energy(&d) - energy(&x)).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dwt_vanishing_moments() {
+        // db3 有 3 阶消失矩：二次多项式（周期延拓后连续的部分）的细节系数为零
+        let size = 64;
+        let dwt = Dwt::<f64>::new(size, &Wavelet::daubechies(3));
+        let x: Vec<f64> = (0..size).map(|i| 0.5 + 0.1 * i as f64 - 0.002 * (i * i) as f64).collect();
+        let (_, d) = dwt.dwt(&x);
+        // 只有跨越首尾边界的系数不为零
+        for &v in &d[2..size / 2 - 1] {
+            assert!(v.abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_dwt_f32() {
+        let dwt = Dwt::<f32>::new(16, &Wavelet::daubechies(4));
+        let x: Vec<f32> = random_signal(16).iter().map(|&v| v as f32).collect();
+        let (a, d) = dwt.dwt(&x);
+        for (u, v) in x.iter().zip(dwt.idwt(&a, &d)) {
+            assert!((u - v).abs() < 1e-5);
+        }
+    }
+
+    const MODES: [ExtensionMode; 8] = [
+        ExtensionMode::Zero,
+        ExtensionMode::Constant,
+        ExtensionMode::Symmetric,
+        ExtensionMode::Reflect,
+        ExtensionMode::Periodic,
+        ExtensionMode::Periodization,
+        ExtensionMode::Smooth,
+        ExtensionMode::Antisymmetric,
+    ];
+
+    #[test]
+    fn test_dwt_modes() {
+        // 对称延拓下 [1, 2, 3] 的末尾补 3
+        let dwt = Dwt::<f64>::with_mode(3, &Wavelet::haar(), ExtensionMode::Symmetric);
+        let (a, _) = dwt.dwt(&[1.0, 2.0, 3.0]);
+        let s = core::f64::consts::FRAC_1_SQRT_2;
+        assert!((a[0] - 3.0 * s).abs() < 1e-12 && (a[1] - 6.0 * s).abs() < 1e-12);
+        // 其余模式下末尾分别补 2、4、-3
+        for (mode, pad) in [(ExtensionMode::Reflect, 2.0), (ExtensionMode::Smooth, 4.0), (ExtensionMode::Antisymmetric, -3.0)] {
+            let (a, d) = Dwt::<f64>::with_mode(3, &Wavelet::haar(), mode).dwt(&[1.0, 2.0, 3.0]);
+            assert!((a[1] - (3.0 + pad) * s).abs() < 1e-12 && (d[1] - (3.0 - pad) * s).abs() < 1e-12, "{:?}", mode);
+        }
+
+        for mode in MODES {
+            for order in [1, 2, 5, 8] {
+                for size in [1, 3, 10, 17, 32] {
+                    let wavelet = Wavelet::daubechies(order);
+                    let dwt = Dwt::<f64>::with_mode(size, &wavelet, mode);
+                    let expected = match mode {
+                        ExtensionMode::Periodization => size.div_ceil(2),
+                        _ => (size + 2 * order - 1) / 2,
+                    };
+                    assert_eq!(dwt.coefficient_len(), expected);
+                    let x = random_signal(size);
+                    let (a, d) = dwt.dwt(&x);
+                    for (u, v) in x.iter().zip(&dwt.idwt(&a, &d)) {
+                        assert!((u - v).abs() < 1e-9, "{:?} db{} size={}", mode, order, size);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dwt_wavedec() {
+        for mode in MODES {
+            for (order, size) in [(1, 64), (2, 100), (4, 257), (6, 1000)] {
+                let wavelet = Wavelet::daubechies(order);
+                let level = dwt_max_level(size, &wavelet);
+                assert!(level >= 2);
+                let x = random_signal(size);
+                let coeffs = wavedec(&x, &wavelet, level, mode);
+                assert_eq!(coeffs.level(), level);
+                assert_eq!(coeffs.signal_len(), size);
+                // 每一层的长度由上一层的近似系数决定
+                let mut n = size;
+                for d in coeffs.details.iter().rev() {
+                    n = mode.coefficient_len(n, wavelet.filter_len());
+                    assert_eq!(d.len(), n);
+                }
+                assert_eq!(coeffs.approx.len(), n);
+
+                let y = waverec(&coeffs, &wavelet, mode);
+                assert_eq!(y.len(), size);
+                for (u, v) in x.iter().zip(&y) {
+                    assert!((u - v).abs() < 1e-9, "{:?} db{} size={}", mode, order, size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dwt_max_level() {
+        assert_eq!(dwt_max_level(1000, &Wavelet::haar()), 9);
+        assert_eq!(dwt_max_level(1000, &Wavelet::daubechies(4)), 7);
+        assert_eq!(dwt_max_level(5, &Wavelet::daubechies(4)), 0);
+        assert_eq!(dwt_max_level(1, &Wavelet::haar()), 0);
+    }
+
+    #[test]
+    fn test_dwt_flatten() {
+        let wavelet = Wavelet::daubechies(3);
+        let x = random_signal(200);
+        let coeffs = wavedec(&x, &wavelet, 4, ExtensionMode::Symmetric);
+        let flat = coeffs.flatten();
+        assert_eq!(flat.len(), coeffs.lengths().iter().sum::<usize>());
+        assert_eq!(coeffs.unflatten(&flat), coeffs);
+
+        // 硬阈值去噪：平滑信号加噪声后，细节系数置零能减小误差
+        let clean: Vec<f64> = (0..256).map(|i| (i as f64 / 40.0).sin()).collect();
+        let noisy: Vec<f64> = clean.iter().zip(random_signal(256)).map(|(c, n)| c + 0.1 * n).collect();
+        let coeffs = wavedec(&noisy, &wavelet, 4, ExtensionMode::Symmetric);
+        let approx_len = coeffs.approx.len();
+        let flat: Vec<f64> = coeffs
+            .flatten()
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| if i < approx_len || v.abs() > 0.3 { v } else { 0.0 })
+            .collect();
+        let denoised = waverec(&coeffs.unflatten(&flat), &wavelet, ExtensionMode::Symmetric);
+        let error = |y: &[f64]| y.iter().zip(&clean).map(|(a, b)| (a - b) * (a - b)).sum::<f64>();
+        assert!(error(&denoised) < 0.5 * error(&noisy));
+    }
+
+    fn random_matrix(row: usize, col: usize) -> DMatrix<f64> {
+        let mut rng = rand::thread_rng();
+        DMatrix::from_fn(row, col, |_, _| rng.gen_range(-1.0..=1.0))
+    }
+
+    #[test]
+    fn test_dwt2_haar() {
+        let dwt = Dwt2D::<f64>::new(2, 2, &Wavelet::haar());
+        let (a, b, c, d) = (1.0, 2.0, 5.0, 9.0);
+        let bands = dwt.dwt2(&DMatrix::from_row_slice(2, 2, &[a, b, c, d]));
+        assert!((bands.ll[(0, 0)] - (a + b + c + d) / 2.0).abs() < 1e-12);
+        assert!((bands.lh[(0, 0)] - (a + b - c - d) / 2.0).abs() < 1e-12);
+        assert!((bands.hl[(0, 0)] - (a - b + c - d) / 2.0).abs() < 1e-12);
+        assert!((bands.hh[(0, 0)] - (a - b - c + d) / 2.0).abs() < 1e-12);
+
+        // 上下两半亮度不同的图像只在 LH（水平边缘）中有细节
+        let image = DMatrix::from_fn(8, 8, |i, _| if i < 3 { 1.0 } else { 0.0 });
+        let bands = Dwt2D::<f64>::new(8, 8, &Wavelet::haar()).dwt2(&image);
+        assert!(bands.lh.abs().max() > 0.5);
+        assert!(bands.hl.abs().max() < 1e-12 && bands.hh.abs().max() < 1e-12);
+    }
+
+    #[test]
+    fn test_dwt2_reconstruction() {
+        for mode in MODES {
+            for order in [1, 2, 4] {
+                for (row, col) in [(8, 8), (7, 12), (15, 9), (1, 5), (33, 20)] {
+                    let wavelet = Wavelet::daubechies(order);
+                    let dwt = Dwt2D::<f64>::with_mode(row, col, &wavelet, mode);
+                    let x = random_matrix(row, col);
+                    let bands = dwt.dwt2(&x);
+                    assert_eq!(bands.ll.shape(), dwt.subband_shape());
+                    assert_eq!(bands.hh.shape(), dwt.subband_shape());
+                    let y = dwt.idwt2(&bands);
+                    assert!((y - &x).abs().max() < 1e-9, "{:?} db{} {}x{}", mode, order, row, col);
+
+                    if mode == ExtensionMode::Periodization && row % 2 == 0 && col % 2 == 0 {
+                        let energy: f64 = [&bands.ll, &bands.lh, &bands.hl, &bands.hh].iter().map(|b| b.norm_squared()).sum();
+                        assert!((energy - x.norm_squared()).abs() < 1e-9);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dwt2_wavedec() {
+        for mode in MODES {
+            for (row, col, level) in [(64, 64, 3), (75, 101, 3), (31, 16, 2)] {
+                let wavelet = Wavelet::daubechies(2);
+                let x = random_matrix(row, col);
+                let coeffs = wavedec2(&x, &wavelet, level, mode);
+                assert_eq!(coeffs.level(), level);
+                assert_eq!(coeffs.shape(), (row, col));
+                assert_eq!(coeffs.approx.shape(), coeffs.details[0].hh.shape());
+                let y = waverec2(&coeffs, &wavelet, mode);
+                assert_eq!(y.shape(), (row, col));
+                assert!((y - &x).abs().max() < 1e-9, "{:?} {}x{}", mode, row, col);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests_extension {
+    use signal_transforms::dct::{BlockDct2D, Dct2D};
+    use signal_transforms::extension::{pad, pad2, ExtensionMode};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    #[test]
+    fn test_extension_pad() {
+        // 与 pywt.pad([1, 2, 3], 4, mode) 一致
+        let x = [1.0, 2.0, 3.0];
+        let cases = [
+            (ExtensionMode::Zero, [0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0]),
+            (ExtensionMode::Constant, [1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0, 3.0, 3.0]),
+            (ExtensionMode::Symmetric, [3.0, 3.0, 2.0, 1.0, 1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 1.0]),
+            (ExtensionMode::Reflect, [1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0]),
+            (ExtensionMode::Periodic, [3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0]),
+            (ExtensionMode::Periodization, [3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0]),
+            (ExtensionMode::Smooth, [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            (ExtensionMode::Antisymmetric, [3.0, -3.0, -2.0, -1.0, 1.0, 2.0, 3.0, -3.0, -2.0, -1.0, 1.0]),
+        ];
+        for (mode, expected) in cases {
+            assert_eq!(pad(&x, 4, 4, mode), expected, "{:?}", mode);
+        }
+        // 单个样本和空信号
+        for (mode, _) in cases {
+            let expected = if mode == ExtensionMode::Zero { [0.0, 5.0, 0.0] } else if mode == ExtensionMode::Antisymmetric { [-5.0, 5.0, -5.0] } else { [5.0; 3] };
+            assert_eq!(pad(&[5.0], 1, 1, mode), expected, "{:?}", mode);
+            assert_eq!(pad::<f64>(&[], 1, 2, mode), [0.0; 3]);
+        }
+    }
+
+    #[test]
+    fn test_extension_sample() {
+        let mut rng = rand::thread_rng();
+        let x: Vec<f64> = (0..7).map(|_| rng.gen_range(-1.0..=1.0)).collect();
+        let n = x.len() as isize;
+        for i in -30..30 {
+            // 对称类延拓关于两端的镜像关系
+            let s = |mode: ExtensionMode, i| mode.sample(&x, i);
+            assert_eq!(s(ExtensionMode::Symmetric, i), s(ExtensionMode::Symmetric, -1 - i));
+            assert_eq!(s(ExtensionMode::Symmetric, i), s(ExtensionMode::Symmetric, 2 * n - 1 - i));
+            assert_eq!(s(ExtensionMode::Reflect, i), s(ExtensionMode::Reflect, -i));
+            assert_eq!(s(ExtensionMode::Reflect, i), s(ExtensionMode::Reflect, 2 * n - 2 - i));
+            assert_eq!(s(ExtensionMode::Antisymmetric, i), -s(ExtensionMode::Antisymmetric, -1 - i));
+            assert_eq!(s(ExtensionMode::Periodic, i), s(ExtensionMode::Periodic, i + n));
+            // 平滑延拓在两端是直线
+            let smooth = s(ExtensionMode::Smooth, i);
+            if i < 0 {
+                assert!((smooth - (x[0] - i as f64 * (x[0] - x[1]))).abs() < 1e-12);
+            } else if i >= n {
+                assert!((smooth - (x[6] + (i - 6) as f64 * (x[6] - x[5]))).abs() < 1e-12);
+            }
+        }
+        for mode in [ExtensionMode::Zero, ExtensionMode::Symmetric, ExtensionMode::Smooth] {
+            assert_eq!(mode.coefficient_len(9, 4), 6);
+        }
+        assert_eq!(ExtensionMode::Periodization.coefficient_len(9, 4), 5);
+    }
+
+    #[test]
+    fn test_extension_pad2() {
+        let data = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let padded = pad2(&data, (1, 2), (2, 1), ExtensionMode::Symmetric);
+        assert_eq!(padded.shape(), (5, 6));
+        assert_eq!(padded.view((1, 2), (2, 3)), data);
+        let expected = DMatrix::from_row_slice(5, 6, &[
+            2.0, 1.0, 1.0, 2.0, 3.0, 3.0,
+            2.0, 1.0, 1.0, 2.0, 3.0, 3.0,
+            5.0, 4.0, 4.0, 5.0, 6.0, 6.0,
+            5.0, 4.0, 4.0, 5.0, 6.0, 6.0,
+            2.0, 1.0, 1.0, 2.0, 3.0, 3.0,
+        ]);
+        assert_eq!(padded, expected);
+        // 可分离：每一行、每一列都是一维延拓
+        let padded = pad2(&data, (3, 0), (0, 2), ExtensionMode::Smooth);
+        for (i, row) in padded.row_iter().enumerate() {
+            let source: Vec<f64> = row.columns(0, 3).iter().copied().collect();
+            assert_eq!(row.iter().copied().collect::<Vec<_>>(), pad(&source, 0, 2, ExtensionMode::Smooth), "row {}", i);
+        }
+    }
+
+    #[test]
+    fn test_block_dct_2d() {
+        let mut rng = rand::thread_rng();
+        let block = 8;
+        let dct = Dct2D::<f64>::new(block, block);
+        for (rows, cols) in [(16, 24), (13, 21), (5, 3)] {
+            let input = DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-1.0..=1.0));
+            for mode in [ExtensionMode::Constant, ExtensionMode::Symmetric, ExtensionMode::Zero] {
+                let plan = BlockDct2D::<f64>::new(rows, cols, block, mode);
+                let (r, c) = plan.coefficient_shape();
+                assert_eq!((r % block, c % block), (0, 0));
+                assert!(r >= rows && r < rows + block && c >= cols && c < cols + block);
+
+                let coefficients = plan.dct_2d(&input);
+                assert_eq!(coefficients.shape(), (r, c));
+                // 每个块等于延拓后图像对应块的 DCT
+                let extended = pad2(&input, (0, r - rows), (0, c - cols), mode);
+                for i in (0..r).step_by(block) {
+                    for j in (0..c).step_by(block) {
+                        let expected = dct.dct_2d(&extended.view((i, j), (block, block)).into_owned());
+                        assert!((coefficients.view((i, j), (block, block)) - expected).abs().max() < 1e-10);
+                    }
+                }
+                assert!((plan.idct_2d(&coefficients) - &input).abs().max() < 1e-10);
+            }
+        }
+    }
+}
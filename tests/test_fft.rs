@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests_fft {
+    use signal_transforms::fft::{fftfreq, fftshift, ifftshift, Fft};
+    use nalgebra::Complex;
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn random_signal(size: usize) -> Vec<Complex<f64>> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| Complex::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0))).collect()
+    }
+
+    /// 按定义计算 DFT
+    fn naive_dft(data: &[Complex<f64>]) -> Vec<Complex<f64>> {
+        let n = data.len();
+        (0..n).map(|k| {
+            data.iter().enumerate().map(|(j, v)| {
+                let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                v * Complex::new(angle.cos(), angle.sin())
+            }).sum()
+        }).collect()
+    }
+
+    fn max_diff(a: &[Complex<f64>], b: &[Complex<f64>]) -> f64 {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).map(|(x, y)| (x - y).norm()).fold(0.0, f64::max)
+    }
+
+    fn check_size(size: usize) {
+        let fft = Fft::<f64>::new(size);
+        assert_eq!(fft.len(), size);
+        let input = random_signal(size);
+        let res = fft.fft(&input);
+        assert!(max_diff(&res, &naive_dft(&input)) < 1e-9 * (size as f64).max(1.0), "size = {}", size);
+        assert!(max_diff(&fft.ifft(&res), &input) < 1e-12 * (size as f64).max(1.0), "size = {}", size);
+    }
+
+    #[test]
+    fn test_fft_small_sizes() {
+        for size in 0..=128 {
+            check_size(size);
+        }
+    }
+
+    #[test]
+    fn test_fft_mixed_radix_and_bluestein() {
+        // 大素数和含大素因子的长度走 Bluestein
+        for size in [97, 101, 257, 2 * 17, 4 * 3 * 19, 1000, 1024, 3 * 5 * 7 * 11, 1031] {
+            check_size(size);
+        }
+    }
+
+    #[test]
+    fn test_fft_f32() {
+        let fft = Fft::new(60);
+        let input: Vec<Complex<f32>> = (0..60).map(|i| Complex::new(i as f32, -(i as f32) / 2.0)).collect();
+        let back = fft.ifft(&fft.fft(&input));
+        let max_diff = input.iter().zip(&back).map(|(x, y)| (x - y).norm()).fold(0.0, f32::max);
+        assert!(max_diff < 1e-3, "max difference = {}", max_diff);
+    }
+
+    #[test]
+    fn test_fft_inplace() {
+        let fft = Fft::<f64>::new(12);
+        let input = random_signal(12);
+        let mut data = input.clone();
+        fft.fft_inplace(&mut data);
+        assert!(max_diff(&data, &fft.fft(&input)) < 1e-12);
+        fft.ifft_inplace(&mut data);
+        assert!(max_diff(&data, &input) < 1e-12);
+    }
+
+    #[test]
+    fn test_fftfreq() {
+        assert_eq!(fftfreq(4, 1.0), vec![0.0, 0.25, -0.5, -0.25]);
+        assert_eq!(fftfreq(5, 0.1), vec![0.0, 2.0, 4.0, -4.0, -2.0]);
+    }
+
+    #[test]
+    fn test_fftshift() {
+        assert_eq!(fftshift(&[0, 1, 2, 3, 4]), vec![3, 4, 0, 1, 2]);
+        assert_eq!(fftshift(&[0, 1, 2, 3]), vec![2, 3, 0, 1]);
+        assert_eq!(ifftshift(&[3, 4, 0, 1, 2]), vec![0, 1, 2, 3, 4]);
+        assert_eq!(ifftshift(&fftshift(&[0, 1, 2, 3, 4, 5])), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    use signal_transforms::fft::{rfftfreq, RealFft, RealFft2D};
+    use nalgebra::DMatrix;
+
+    #[test]
+    fn test_rfft() {
+        let mut rng = rand::thread_rng();
+        for size in (0..=64).chain([97, 100, 257, 1000]) {
+            let rfft = RealFft::<f64>::new(size);
+            let input: Vec<f64> = (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect();
+            let res = rfft.rfft(&input);
+            assert_eq!(res.len(), if size == 0 { 0 } else { size / 2 + 1 });
+
+            let complex: Vec<Complex<f64>> = input.iter().map(|&v| Complex::new(v, 0.0)).collect();
+            let expected = naive_dft(&complex);
+            assert!(max_diff(&res, &expected[..res.len()]) < 1e-9 * (size as f64).max(1.0), "size = {}", size);
+
+            let back = rfft.irfft(&res);
+            let err = input.iter().zip(&back).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+            assert!(err < 1e-12 * (size as f64).max(1.0), "size = {}", size);
+        }
+    }
+
+    #[test]
+    fn test_rfft_2d() {
+        let mut rng = rand::thread_rng();
+        for (rows, cols) in [(1, 1), (4, 6), (5, 7), (8, 3), (6, 16)] {
+            let plan = RealFft2D::<f64>::new(rows, cols);
+            let input = DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-1.0..=1.0));
+            let res = plan.rfft_2d(&input);
+            assert_eq!(res.shape(), (rows, cols / 2 + 1));
+
+            // 与按定义计算的二维 DFT 比较
+            for u in 0..rows {
+                for v in 0..cols / 2 + 1 {
+                    let mut expected = Complex::new(0.0, 0.0);
+                    for i in 0..rows {
+                        for j in 0..cols {
+                            let angle = -2.0 * PI * ((u * i) as f64 / rows as f64 + (v * j) as f64 / cols as f64);
+                            expected += Complex::new(angle.cos(), angle.sin()) * input[(i, j)];
+                        }
+                    }
+                    assert!((res[(u, v)] - expected).norm() < 1e-9);
+                }
+            }
+
+            let back = plan.irfft_2d(&res);
+            assert!((back - &input).abs().max() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rfftfreq() {
+        assert_eq!(rfftfreq(4, 1.0), vec![0.0, 0.25, 0.5]);
+        assert_eq!(rfftfreq(5, 0.1), vec![0.0, 2.0, 4.0]);
+    }
+}
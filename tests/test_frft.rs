@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests_frft {
+    use signal_transforms::fft::{fftshift, ifftshift, Fft};
+    use signal_transforms::frft::{frft, DiscreteFrft};
+    use nalgebra::Complex;
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn random_signal(size: usize) -> Vec<Complex<f64>> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| Complex::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0))).collect()
+    }
+
+    fn max_diff(a: &[Complex<f64>], b: &[Complex<f64>]) -> f64 {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).map(|(x, y)| (x - y).norm()).fold(0.0, f64::max)
+    }
+
+    fn centered_dft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+        let scale = (x.len() as f64).sqrt();
+        fftshift(&Fft::new(x.len()).fft(&ifftshift(x))).iter().map(|v| v / scale).collect()
+    }
+
+    /// 以 N/2 为原点、间隔 1/√N 采样的函数
+    fn sampled(n: usize, f: impl Fn(f64) -> Complex<f64>) -> Vec<Complex<f64>> {
+        (0..n).map(|i| f((i as f64 - (n / 2) as f64) / (n as f64).sqrt())).collect()
+    }
+
+    #[test]
+    fn test_frft_special_orders() {
+        for n in [1, 2, 7, 16] {
+            let x = random_signal(n);
+            assert!(max_diff(&frft(&x, 0.0), &x) < 1e-12);
+            assert!(max_diff(&frft(&x, 4.0), &x) < 1e-12);
+            assert!(max_diff(&frft(&x, 1.0), &centered_dft(&x)) < 1e-9);
+            assert!(max_diff(&frft(&frft(&x, 1.0), 1.0), &frft(&x, 2.0)) < 1e-9);
+            assert!(max_diff(&frft(&frft(&x, 1.0), -1.0), &x) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_frft_gaussian() {
+        // e^{-πt²} 是所有阶数的本征函数
+        let n = 128;
+        let gauss = sampled(n, |t| Complex::new((-PI * t * t).exp(), 0.0));
+        for a in [0.3, 0.5, 0.77, 1.2, 1.8, 2.5, -0.6] {
+            assert!(max_diff(&frft(&gauss, a), &gauss) < 1e-6, "order {}", a);
+        }
+    }
+
+    #[test]
+    fn test_frft_chirp_concentration() {
+        // 线性调频信号在匹配的分数阶域中聚成一个窄峰
+        let n = 256;
+        let a: f64 = 0.6;
+        let rate = -1.0 / (a * PI / 2.0).tan();
+        let chirp = sampled(n, |t| Complex::from_polar((-PI * t * t / 16.0).exp(), PI * rate * t * t));
+        let energy = |x: &[Complex<f64>]| {
+            let total: f64 = x.iter().map(|v| v.norm_sqr()).sum();
+            let peak = x.iter().map(|v| v.norm_sqr()).fold(0.0, f64::max);
+            peak / total
+        };
+        let focused = frft(&chirp, a);
+        assert!(energy(&focused) > 5.0 * energy(&chirp));
+        assert!(energy(&focused) > 5.0 * energy(&frft(&chirp, 1.0)));
+    }
+
+    #[test]
+    fn test_frft_additive_approx() {
+        let n = 128;
+        let x = sampled(n, |t| Complex::from_polar((-PI * t * t / 2.0).exp(), 1.5 * t));
+        let two_steps = frft(&frft(&x, 0.4), 0.5);
+        assert!(max_diff(&two_steps, &frft(&x, 0.9)) < 1e-2);
+        assert!(max_diff(&frft(&frft(&x, 0.7), -0.7), &x) < 1e-2);
+    }
+
+    #[test]
+    fn test_discrete_frft() {
+        for n in [1, 2, 3, 8, 15, 32] {
+            let plan = DiscreteFrft::<f64>::new(n);
+            let x = random_signal(n);
+            let norm = |v: &[Complex<f64>]| v.iter().map(|c| c.norm_sqr()).sum::<f64>();
+
+            assert!(max_diff(&plan.frft(&x, 0.0), &x) < 1e-9);
+            assert!(max_diff(&plan.frft(&x, 1.0), &centered_dft(&x)) < 1e-9, "n = {}", n);
+            for (a, b) in [(0.3, 0.45), (1.7, -0.2), (2.5, 3.25)] {
+                let y = plan.frft(&x, a);
+                // 酉变换保持能量
+                assert!((norm(&y) - norm(&x)).abs() < 1e-9);
+                // 阶数可加
+                assert!(max_diff(&plan.frft(&y, b), &plan.frft(&x, a + b)) < 1e-9);
+                assert!(max_diff(&plan.ifrft(&y, a), &x) < 1e-9);
+            }
+        }
+    }
+}
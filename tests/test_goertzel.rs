@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests_goertzel {
+    use signal_transforms::fft::Fft;
+    use signal_transforms::goertzel::{goertzel, goertzel_bin, GoertzelDetector};
+    use nalgebra::Complex;
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn random_signal(size: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect()
+    }
+
+    /// 直接按定义计算 DTFT
+    fn dtft(signal: &[f64], omega: f64) -> Complex<f64> {
+        signal.iter().enumerate().map(|(n, &x)| Complex::from_polar(x, -omega * n as f64)).sum()
+    }
+
+    #[test]
+    fn test_goertzel_matches_fft() {
+        for n in [1, 8, 205, 256] {
+            let signal = random_signal(n);
+            let complex: Vec<Complex<f64>> = signal.iter().map(|&v| Complex::new(v, 0.0)).collect();
+            let spectrum = Fft::new(n).fft(&complex);
+            for (k, expected) in spectrum.iter().enumerate() {
+                assert!((goertzel_bin(&signal, k as f64) - expected).norm() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_goertzel_non_integer_bin() {
+        let signal = random_signal(100);
+        for k in [0.5, 3.25, 17.9, 49.99] {
+            let expected = dtft(&signal, 2.0 * PI * k / 100.0);
+            assert!((goertzel_bin(&signal, k) - expected).norm() < 1e-9);
+        }
+        let fs = 8000.0;
+        for f in [697.0, 1209.0, 1336.5] {
+            assert!((goertzel(&signal, f, fs) - dtft(&signal, 2.0 * PI * f / fs)).norm() < 1e-9);
+        }
+        assert_eq!(goertzel_bin::<f64>(&[], 1.0), Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_goertzel_f32() {
+        let signal: Vec<f32> = random_signal(64).iter().map(|&v| v as f32).collect();
+        let expected = dtft(&signal.iter().map(|&v| v as f64).collect::<Vec<_>>(), 2.0 * PI * 5.5 / 64.0);
+        let res = goertzel_bin(&signal, 5.5f32);
+        assert!((res.re as f64 - expected.re).abs() < 1e-3 && (res.im as f64 - expected.im).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_detector_dtmf() {
+        // DTMF "5"：770 Hz + 1336 Hz
+        let fs = 8000.0;
+        let block = 205;
+        let targets = [697.0, 770.0, 852.0, 941.0, 1209.0, 1336.0, 1477.0];
+        let signal: Vec<f64> = (0..block * 4)
+            .map(|n| {
+                let t = n as f64 / fs;
+                (2.0 * PI * 770.0 * t + 0.3).sin() + 0.8 * (2.0 * PI * 1336.0 * t).sin()
+            })
+            .collect();
+
+        let mut detector = GoertzelDetector::new(&targets, fs, block);
+        let mut rng = rand::thread_rng();
+        let mut blocks = Vec::new();
+        let mut pos = 0;
+        while pos < signal.len() {
+            let len = rng.gen_range(1..=300).min(signal.len() - pos);
+            blocks.extend(detector.process(&signal[pos..pos + len]));
+            pos += len;
+        }
+        assert_eq!(blocks.len(), 4);
+
+        for (b, tones) in blocks.iter().enumerate() {
+            assert_eq!(tones.len(), targets.len());
+            let start = b * block;
+            for tone in tones {
+                // 与一次性函数的结果一致
+                let expected = goertzel(&signal[start..start + block], tone.frequency, fs);
+                assert!((tone.magnitude - expected.norm()).abs() < 1e-9);
+                assert!((Complex::from_polar(tone.magnitude, tone.phase) - expected).norm() < 1e-9);
+            }
+            let loudest: Vec<f64> = {
+                let mut sorted = tones.clone();
+                sorted.sort_by(|a, b| b.magnitude.partial_cmp(&a.magnitude).unwrap());
+                sorted.iter().take(2).map(|t| t.frequency).collect()
+            };
+            assert_eq!(loudest, vec![770.0, 1336.0]);
+        }
+
+        // 未满一块的数据在 reset 后被丢弃
+        assert!(detector.process(&signal[..100]).is_empty());
+        detector.reset();
+        assert_eq!(detector.process(&signal[..block]).len(), 1);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests_hilbert {
+    use signal_transforms::hilbert::{envelope, hilbert, instantaneous_frequency, instantaneous_phase, unwrap_phase, HilbertFir};
+    use signal_transforms::window::Window;
+    use nalgebra::Complex;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_hilbert_sinusoid() {
+        // 整数个周期的余弦，解析信号是复指数
+        for n in [64, 75] {
+            let omega = 2.0 * PI * 5.0 / n as f64;
+            let signal: Vec<f64> = (0..n).map(|i| (omega * i as f64 + 0.4).cos()).collect();
+            let analytic = hilbert(&signal);
+            for (i, v) in analytic.iter().enumerate() {
+                assert!((v - Complex::from_polar(1.0, omega * i as f64 + 0.4)).norm() < 1e-9);
+            }
+        }
+        assert!(hilbert::<f64>(&[]).is_empty());
+        // 零频保持不变
+        assert!(hilbert(&[2.0f64; 8]).iter().all(|v| (v - Complex::new(2.0, 0.0)).norm() < 1e-12));
+    }
+
+    #[test]
+    fn test_envelope_am() {
+        let n = 512;
+        let signal: Vec<f64> = (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                (1.0 + 0.5 * (2.0 * PI * 3.0 * t).cos()) * (2.0 * PI * 60.0 * t).cos()
+            })
+            .collect();
+        let env = envelope(&signal);
+        for (i, e) in env.iter().enumerate() {
+            let expected = 1.0 + 0.5 * (2.0 * PI * 3.0 * i as f64 / n as f64).cos();
+            assert!((e - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_instantaneous_attributes() {
+        let fs = 1000.0;
+        let n = 1000;
+        // 频率从 50 Hz 线性升到 150 Hz 的线性调频信号
+        let phase = |t: f64| 2.0 * PI * (50.0 * t + 50.0 * t * t);
+        let signal: Vec<f64> = (0..n).map(|i| phase(i as f64 / fs).cos()).collect();
+
+        let inst_phase = instantaneous_phase(&signal);
+        let freq = instantaneous_frequency(&signal, fs);
+        assert_eq!(freq.len(), n - 1);
+        // 避开两端的边缘效应
+        for i in 100..n - 100 {
+            let t = i as f64 / fs;
+            let expected_phase = phase(t) - phase(0.1) + inst_phase[100];
+            assert!((inst_phase[i] - expected_phase).abs() < 0.05);
+            let expected_freq = 50.0 + 100.0 * (t + 0.5 / fs);
+            assert!((freq[i] - expected_freq).abs() < 1.0, "{} {}", freq[i], expected_freq);
+        }
+    }
+
+    #[test]
+    fn test_unwrap_phase() {
+        let truth: Vec<f64> = (0..50).map(|i| 0.9 * i as f64 - 3.0).collect();
+        let wrapped: Vec<f64> = truth.iter().map(|p| p.sin().atan2(p.cos())).collect();
+        let res = unwrap_phase(&wrapped);
+        let offset = res[0] - truth[0];
+        assert!(res.iter().zip(&truth).all(|(a, b)| (a - b - offset).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_hilbert_fir() {
+        let mut fir = HilbertFir::<f64>::new(101, Window::Blackman);
+        assert_eq!(fir.delay(), 50);
+        let c = fir.coefficients();
+        // 反对称，偶数下标为零
+        assert!((0..101).all(|k| (c[k] + c[100 - k]).abs() < 1e-15));
+        assert!((0..101).step_by(2).all(|k| c[k] == 0.0));
+
+        for f in [0.08, 0.2, 0.35] {
+            fir.reset();
+            let signal: Vec<f64> = (0..600).map(|i| (2.0 * PI * f * i as f64).cos()).collect();
+            let mut out = fir.process(&signal[..123]);
+            out.extend(fir.process(&signal[123..]));
+            for (i, v) in out.iter().enumerate().skip(101) {
+                let expected = Complex::from_polar(1.0, 2.0 * PI * f * (i - 50) as f64);
+                assert!((v - expected).norm() < 0.01, "{} {} {}", f, v, expected);
+            }
+        }
+    }
+}
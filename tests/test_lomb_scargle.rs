@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests_lomb_scargle {
+    use signal_transforms::lomb_scargle::{
+        FalseAlarmMethod, FrequencyGrid, LombScargle, LombScargleModel, LombScargleNormalization,
+    };
+    use nalgebra::{DMatrix, DVector};
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    /// 带抖动和缺口的采样时间，外加带噪声的正弦
+    fn irregular_series(size: usize, frequency: f64, noise: f64) -> (Vec<f64>, Vec<f64>) {
+        let mut rng = rand::thread_rng();
+        let mut t: Vec<f64> = (0..size)
+            .map(|i| i as f64 + rng.gen_range(-0.4..0.4))
+            .filter(|t| !(30.0..45.0).contains(t))
+            .collect();
+        t.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let y = t
+            .iter()
+            .map(|&t| 3.0 + (2.0 * PI * frequency * t + 0.3).sin() + noise * rng.gen_range(-1.0..1.0))
+            .collect();
+        (t, y)
+    }
+
+    /// 最小二乘拟合解释的方差比例
+    fn explained_variance(t: &[f64], y: &[f64], frequency: f64, offset: bool) -> f64 {
+        let n = t.len();
+        let mean = y.iter().sum::<f64>() / n as f64;
+        let centered = DVector::from_iterator(n, y.iter().map(|v| v - mean));
+        let cols = if offset { 3 } else { 2 };
+        let design = DMatrix::from_fn(n, cols, |i, j| match j {
+            0 => (2.0 * PI * frequency * t[i]).cos(),
+            1 => (2.0 * PI * frequency * t[i]).sin(),
+            _ => 1.0,
+        });
+        let coef = design.clone().svd(true, true).solve(&centered, 1e-12).unwrap();
+        let residual = &centered - design * coef;
+        1.0 - residual.norm_squared() / centered.norm_squared()
+    }
+
+    #[test]
+    fn test_lomb_scargle_least_squares() {
+        let (t, y) = irregular_series(120, 0.13, 0.5);
+        for (model, offset) in [(LombScargleModel::Standard, false), (LombScargleModel::FloatingMean, true)] {
+            let ls = LombScargle::<f64>::new(&t, &y, model, LombScargleNormalization::Standard);
+            let frequencies = [0.01, 0.05, 0.13, 0.31, 0.47];
+            for (&f, p) in frequencies.iter().zip(ls.power(&frequencies)) {
+                assert!((p - explained_variance(&t, &y, f, offset)).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lomb_scargle_detection() {
+        let (t, y) = irregular_series(200, 0.21, 1.0);
+        let ls = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, LombScargleNormalization::Standard);
+        let grid = ls.autofrequency(10.0, 1.0);
+        let frequencies = grid.frequencies();
+        let power = ls.power_grid(&grid);
+        let (best, &peak) = power.iter().enumerate().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).unwrap();
+        assert!((frequencies[best] - 0.21).abs() < 2e-3);
+        assert!(power.iter().all(|&p| (0.0..=1.0).contains(&p)));
+
+        let fmax = *frequencies.last().unwrap();
+        for method in [FalseAlarmMethod::Naive, FalseAlarmMethod::Davies, FalseAlarmMethod::Baluev] {
+            assert!(ls.false_alarm_probability(peak, fmax, method) < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_lomb_scargle_fast() {
+        let (t, y) = irregular_series(500, 0.05, 2.0);
+        for model in [LombScargleModel::Standard, LombScargleModel::FloatingMean] {
+            let ls = LombScargle::<f64>::new(&t, &y, model, LombScargleNormalization::Standard);
+            for grid in [ls.autofrequency(5.0, 2.0), FrequencyGrid { f0: 0.3, df: 1e-3, len: 301 }] {
+                let fast = ls.power_grid(&grid);
+                let exact = ls.power(&grid.frequencies());
+                assert_eq!(fast.len(), grid.len);
+                for (a, b) in fast.iter().zip(&exact) {
+                    assert!((a - b).abs() < 1e-7);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lomb_scargle_normalization() {
+        let (t, y) = irregular_series(80, 0.3, 0.5);
+        let frequencies: Vec<f64> = (1..50).map(|k| k as f64 * 0.01).collect();
+        let power = |normalization| {
+            LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, normalization).power(&frequencies)
+        };
+        let standard = power(LombScargleNormalization::Standard);
+        let model = power(LombScargleNormalization::Model);
+        let log = power(LombScargleNormalization::Log);
+        let psd = power(LombScargleNormalization::Psd);
+        let n = t.len() as f64;
+        let mean = y.iter().sum::<f64>() / n;
+        let chi2_ref = y.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+        for i in 0..frequencies.len() {
+            let p = standard[i];
+            assert!((model[i] - p / (1.0 - p)).abs() < 1e-9);
+            assert!((log[i] + (1.0 - p).ln()).abs() < 1e-9);
+            assert!((psd[i] - 0.5 * chi2_ref * p).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_lomb_scargle_false_alarm() {
+        let (t, y) = irregular_series(100, 0.1, 1.0);
+        let fmax = 0.5;
+        for normalization in [LombScargleNormalization::Standard, LombScargleNormalization::Log] {
+            let ls = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, normalization);
+            let standard = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, LombScargleNormalization::Standard);
+            for method in [FalseAlarmMethod::Naive, FalseAlarmMethod::Davies, FalseAlarmMethod::Baluev] {
+                let mut last = 1.0;
+                for k in 1..20 {
+                    let p = k as f64 * 0.02;
+                    // 同一个峰值在不同归一化下的虚警概率相同
+                    let fap = ls.false_alarm_probability(standard_to(normalization, p), fmax, method);
+                    assert!((fap - standard.false_alarm_probability(p, fmax, method)).abs() < 1e-12);
+                    assert!((0.0..=1.0).contains(&fap));
+                    assert!(fap <= last);
+                    last = fap;
+                }
+            }
+            let p = 0.2;
+            let davies = ls.false_alarm_probability(standard_to(normalization, p), fmax, FalseAlarmMethod::Davies);
+            let baluev = ls.false_alarm_probability(standard_to(normalization, p), fmax, FalseAlarmMethod::Baluev);
+            assert!(baluev <= davies);
+        }
+    }
+
+    #[test]
+    fn test_lomb_scargle_false_alarm_few_samples() {
+        let methods = [FalseAlarmMethod::Naive, FalseAlarmMethod::Davies, FalseAlarmMethod::Baluev];
+        // N ≤ 3 时没有剩余自由度，任何峰值都不显著
+        for (t, y) in [(vec![0.0, 1.3], vec![1.0, -1.0]), (vec![0.0, 1.3, 2.1], vec![1.0, -1.0, 0.5])] {
+            let ls = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, LombScargleNormalization::Standard);
+            for method in methods {
+                for p in [0.0, 0.5, 0.99, 1.0] {
+                    assert_eq!(ls.false_alarm_probability(p, 2.0, method), 1.0);
+                }
+            }
+        }
+        let (t, y) = (vec![0.0, 1.3, 2.1, 3.7], vec![1.0, -1.0, 0.5, 0.2]);
+        let ls = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, LombScargleNormalization::Standard);
+        for method in methods {
+            let fap = ls.false_alarm_probability(0.5, 2.0, method);
+            assert!((0.0..=1.0).contains(&fap), "{:?} {}", method, fap);
+        }
+    }
+
+#[test]
+    fn test_lomb_scargle_constant_signal() {
+        let (t, _) = irregular_series(50, 0.1, 0.0);
+        let y = vec![2.5; t.len()];
+        let frequencies = [0.05, 0.1, 0.3];
+        for normalization in [
+            LombScargleNormalization::Standard,
+            LombScargleNormalization::Model,
+            LombScargleNormalization::Log,
+            LombScargleNormalization::Psd,
+        ] {
+            let ls = LombScargle::<f64>::new(&t, &y, LombScargleModel::FloatingMean, normalization);
+            assert!(ls.power(&frequencies).iter().all(|&p| p == 0.0), "{:?}", normalization);
+            for method in [FalseAlarmMethod::Naive, FalseAlarmMethod::Davies, FalseAlarmMethod::Baluev] {
+                for power in [0.0, 0.5, 1.0] {
+                    let fap = ls.false_alarm_probability(power, 0.5, method);
+                    assert!((0.0..=1.0).contains(&fap), "{:?} {:?}", normalization, method);
+                    // Psd 的功率以数据方差为单位，常数信号下任何峰值都不显著
+                    if normalization == LombScargleNormalization::Psd {
+                        assert_eq!(fap, 1.0);
+                    }
+                }
+            }
+        }
+    }
+
+        fn standard_to(normalization: LombScargleNormalization, p: f64) -> f64 {
+        match normalization {
+            LombScargleNormalization::Log => -(1.0 - p).ln(),
+            _ => p,
+        }
+    }
+}
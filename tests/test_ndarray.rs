@@ -0,0 +1,91 @@
+#[cfg(test)]
+#[cfg(feature = "ndarray")]
+mod tests_ndarray {
+    use signal_transforms::dct::{Dct, Dct2D};
+    use signal_transforms::dst::{Dst, Dst2D};
+    use nalgebra::DMatrix;
+    use ndarray::{Array2, Array3, ShapeBuilder, Axis};
+    use rand::Rng;
+
+    const EPSILON: f32 = 1e-2;
+
+    fn generate_random_2d(rows: usize, cols: usize) -> Vec<f32> {
+        let mut rng = rand::thread_rng();
+        (0..rows * cols).map(|_| rng.gen_range(0.0..=255.0)).collect()
+    }
+
+    fn assert_close(a: &Array2<f32>, b: &DMatrix<f32>, epsilon: f32) {
+        assert_eq!(a.dim(), b.shape());
+        let max_diff = a.indexed_iter()
+            .map(|((i, j), v)| (v - b[(i, j)]).abs())
+            .fold(0.0, f32::max);
+        assert!(max_diff < epsilon, "max difference = {}", max_diff);
+    }
+
+    #[test]
+    fn test_dct_axis_matches_dmatrix() {
+        let (rows, cols) = (5, 7);
+        let data = generate_random_2d(rows, cols);
+        let matrix = DMatrix::from_row_slice(rows, cols, &data);
+        let c_order = Array2::from_shape_vec((rows, cols), data.clone()).unwrap();
+        // 同样的数据，按列存储
+        let f_order = Array2::from_shape_fn((rows, cols).f(), |(i, j)| data[i * cols + j]);
+
+        for input in [c_order.view(), f_order.view()] {
+            // 沿 axis 1：每行一个信号
+            let dct = Dct::new(cols);
+            let mut out = Array2::zeros((rows, cols).f());
+            dct.dct_axis(input, out.view_mut(), Axis(1));
+            let expected = DMatrix::from_fn(rows, cols, |i, j| dct.dct_1d(&matrix.rows(i, 1).into_owned())[j]);
+            assert_close(&out, &expected, EPSILON);
+
+            let mut back = Array2::zeros((rows, cols));
+            dct.idct_axis(out.view(), back.view_mut(), Axis(1));
+            assert_close(&back, &matrix, EPSILON);
+
+            // 沿 axis 0：每列一个信号
+            let dct = Dct::new(rows);
+            let mut out = Array2::zeros((rows, cols));
+            dct.dct_axis(input, out.view_mut(), Axis(0));
+            let transposed = matrix.transpose();
+            let expected = DMatrix::from_fn(cols, rows, |i, j| dct.dct_1d(&transposed.rows(i, 1).into_owned())[j]).transpose();
+            assert_close(&out, &expected, EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_dst_axis_3d() {
+        let mut rng = rand::thread_rng();
+        let input = Array3::from_shape_fn((3, 6, 4), |_| rng.gen_range(0.0..=255.0f32));
+        let dst = Dst::new(6);
+        let mut out = Array3::zeros((3, 6, 4));
+        dst.dst_axis(input.view(), out.view_mut(), Axis(1));
+        let mut back = Array3::zeros((3, 6, 4).f());
+        dst.idst_axis(out.view(), back.view_mut(), Axis(1));
+        let max_diff = (&input - &back).iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        assert!(max_diff < EPSILON, "max difference = {}", max_diff);
+    }
+
+    #[test]
+    fn test_dct_2d_view_matches_dmatrix() {
+        let (rows, cols) = (6, 9);
+        let data = generate_random_2d(rows, cols);
+        let matrix = DMatrix::from_row_slice(rows, cols, &data);
+        let input = Array2::from_shape_fn((rows, cols).f(), |(i, j)| data[i * cols + j]);
+
+        let dct = Dct2D::new(rows, cols);
+        let mut out = Array2::zeros((rows, cols));
+        dct.dct_2d_view(input.view(), out.view_mut());
+        assert_close(&out, &dct.dct_2d(&matrix), EPSILON);
+
+        let mut back = Array2::zeros((rows, cols).f());
+        dct.idct_2d_view(out.view(), back.view_mut());
+        assert_close(&back, &matrix, EPSILON);
+
+        let dst = Dst2D::new(rows, cols);
+        dst.dst_2d_view(input.view(), out.view_mut());
+        assert_close(&out, &dst.dst_2d(&matrix), EPSILON);
+        dst.idst_2d_view(out.view(), back.view_mut());
+        assert_close(&back, &matrix, EPSILON);
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests_ntt {
+    use signal_transforms::ntt::{convolve_exact, convolve_mod, crt, Ntt, CRT_PRIMES, MOD_998244353};
+    use rand::Rng;
+
+    fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+        let mut res = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = (res as u128 * base as u128 % m as u128) as u64;
+            }
+            base = (base as u128 * base as u128 % m as u128) as u64;
+            exp >>= 1;
+        }
+        res
+    }
+
+    #[test]
+    fn test_ntt_definition() {
+        let mut rng = rand::thread_rng();
+        let p = MOD_998244353;
+        for n in [1, 2, 4, 16, 64] {
+            let ntt = Ntt::new(n);
+            let input: Vec<u64> = (0..n).map(|_| rng.gen_range(0..p)).collect();
+            let res = ntt.ntt(&input);
+            let w = pow_mod(3, (p - 1) / n as u64, p);
+            for (k, &v) in res.iter().enumerate() {
+                let expected = input.iter().enumerate().fold(0u64, |acc, (i, &x)| {
+                    let term = x as u128 * pow_mod(w, (i * k) as u64, p) as u128 % p as u128;
+                    ((acc as u128 + term) % p as u128) as u64
+                });
+                assert_eq!(v, expected);
+            }
+            assert_eq!(ntt.intt(&res), input);
+        }
+    }
+
+    #[test]
+    fn test_ntt_roundtrip_other_primes() {
+        let mut rng = rand::thread_rng();
+        for (p, g) in CRT_PRIMES {
+            let ntt = Ntt::with_modulus(1 << 12, p, g);
+            let input: Vec<u64> = (0..1 << 12).map(|_| rng.gen_range(0..p)).collect();
+            assert_eq!(ntt.intt(&ntt.ntt(&input)), input);
+        }
+    }
+
+    #[test]
+    fn test_convolve_mod() {
+        let mut rng = rand::thread_rng();
+        let p = MOD_998244353;
+        let a: Vec<u64> = (0..37).map(|_| rng.gen_range(0..p)).collect();
+        let b: Vec<u64> = (0..100).map(|_| rng.gen_range(0..p)).collect();
+        let mut expected = vec![0u128; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] = (expected[i + j] + x as u128 * y as u128) % p as u128;
+            }
+        }
+        let res = convolve_mod(&a, &b, p, 3);
+        assert!(res.iter().zip(&expected).all(|(&r, &e)| r as u128 == e));
+        assert!(convolve_mod(&[], &b, p, 3).is_empty());
+    }
+
+    #[test]
+    fn test_crt() {
+        let moduli: Vec<u64> = CRT_PRIMES.iter().map(|&(p, _)| p).collect();
+        let product: u128 = moduli.iter().map(|&m| m as u128).product();
+        for x in [0u128, 1, 123_456_789_012_345_678_901, product - 1] {
+            let residues: Vec<u64> = moduli.iter().map(|&m| (x % m as u128) as u64).collect();
+            assert_eq!(crt(&residues, &moduli), x);
+        }
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        // 两个接近 2^64 的素数，余数也接近模数
+        let moduli = [18_446_744_073_709_551_557u64, 18_446_744_073_709_551_533];
+        let product = moduli[0] as u128 * moduli[1] as u128;
+        for x in [product - 1, product - 12_345_678_901_234_567_890, product / 2 + 7, moduli[0] as u128 - 1] {
+            let residues: Vec<u64> = moduli.iter().map(|&m| (x % m as u128) as u64).collect();
+            assert_eq!(crt(&residues, &moduli), x);
+        }
+        // 余数不必小于模数
+        assert_eq!(crt(&[u64::MAX, u64::MAX], &moduli), u64::MAX as u128);
+    }
+
+    #[test]
+    #[should_panic(expected = "product of moduli overflows u128")]
+    fn test_crt_overflow() {
+        // 前两个模数之积接近 2^128，再乘 7 溢出，应在累加 x 之前报错
+        crt(&[0, 0, 1], &[18_446_744_073_709_551_557, 18_446_744_073_709_551_533, 7]);
+    }
+
+    #[test]
+    fn test_convolve_exact_bigint() {
+        // 以 10^6 为基的大整数乘法
+        let mut rng = rand::thread_rng();
+        let base = 1_000_000u64;
+        let a: Vec<u64> = (0..500).map(|_| rng.gen_range(0..base)).collect();
+        let b: Vec<u64> = (0..300).map(|_| rng.gen_range(0..base)).collect();
+        let res = convolve_exact(&a, &b);
+        let mut expected = vec![0u128; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] += x as u128 * y as u128;
+            }
+        }
+        assert_eq!(res, expected);
+
+        // 系数超过单个素数的范围
+        let big = [u32::MAX as u64; 4];
+        let res = convolve_exact(&big, &big);
+        assert_eq!(res[3], 4 * (u32::MAX as u128).pow(2));
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests_nufft {
+    use signal_transforms::nufft::{Nufft, Nufft2D, NufftKernel};
+    use nalgebra::{Complex, DMatrix};
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    fn random_complex(size: usize) -> Vec<Complex<f64>> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| Complex::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0))).collect()
+    }
+
+    fn random_points(size: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| rng.gen_range(-PI..PI)).collect()
+    }
+
+    fn modes(m: usize) -> impl Iterator<Item = f64> {
+        (0..m).map(move |i| i as f64 - (m / 2) as f64)
+    }
+
+    /// 相对 ℓ² 误差
+    fn relative_error<'a>(a: impl IntoIterator<Item = &'a Complex<f64>>, b: impl IntoIterator<Item = &'a Complex<f64>>) -> f64 {
+        let (mut num, mut den) = (0.0, 0.0);
+        for (x, y) in a.into_iter().zip(b) {
+            num += (x - y).norm_sqr();
+            den += y.norm_sqr();
+        }
+        (num / den).sqrt()
+    }
+
+    #[test]
+    fn test_nufft_1d() {
+        for kernel in [NufftKernel::KaiserBessel, NufftKernel::ExpSemicircle] {
+            for (m, tol) in [(32, 1e-3), (51, 1e-6), (100, 1e-9), (7, 1e-12)] {
+                let plan = Nufft::<f64>::new(m, tol, kernel);
+                let x = random_points(200);
+                let c = random_complex(200);
+                let f = plan.type1(&x, &c);
+                let expected: Vec<Complex<f64>> = modes(m)
+                    .map(|k| x.iter().zip(&c).map(|(&xj, &cj)| cj * Complex::from_polar(1.0, -k * xj)).sum())
+                    .collect();
+                let err = relative_error(&f, &expected);
+                assert!(err < 10.0 * tol, "{:?} type 1, m = {}, tol = {}: {}", kernel, m, tol, err);
+
+                let coeffs = random_complex(m);
+                let values = plan.type2(&x, &coeffs);
+                let expected: Vec<Complex<f64>> = x
+                    .iter()
+                    .map(|&xj| modes(m).zip(&coeffs).map(|(k, &fk)| fk * Complex::from_polar(1.0, k * xj)).sum())
+                    .collect();
+                let err = relative_error(&values, &expected);
+                assert!(err < 10.0 * tol, "{:?} type 2, m = {}, tol = {}: {}", kernel, m, tol, err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nufft_periodic_points() {
+        // 点的坐标按 2π 取模
+        let plan = Nufft::<f64>::new(16, 1e-8, NufftKernel::ExpSemicircle);
+        let c = random_complex(3);
+        let x = [0.3, -2.0, 3.1];
+        let shifted: Vec<f64> = x.iter().map(|v| v + 4.0 * PI).collect();
+        assert!(relative_error(&plan.type1(&shifted, &c), &plan.type1(&x, &c)) < 1e-10);
+    }
+
+    #[test]
+    fn test_nufft_f32() {
+        let plan = Nufft::<f32>::new(20, 1e-4, NufftKernel::KaiserBessel);
+        let x: Vec<f32> = random_points(50).iter().map(|&v| v as f32).collect();
+        let c: Vec<Complex<f32>> = random_complex(50).iter().map(|v| Complex::new(v.re as f32, v.im as f32)).collect();
+        let f = plan.type1(&x, &c);
+        let expected: Vec<Complex<f64>> = modes(20)
+            .map(|k| x.iter().zip(&c).map(|(&xj, cj)| Complex::new(cj.re as f64, cj.im as f64) * Complex::from_polar(1.0, -k * xj as f64)).sum())
+            .collect();
+        let f: Vec<Complex<f64>> = f.iter().map(|v| Complex::new(v.re as f64, v.im as f64)).collect();
+        assert!(relative_error(&f, &expected) < 1e-3);
+    }
+
+    #[test]
+    fn test_nufft_2d() {
+        for kernel in [NufftKernel::KaiserBessel, NufftKernel::ExpSemicircle] {
+            let (m1, m2, tol) = (12, 9, 1e-7);
+            let plan = Nufft2D::<f64>::new(m1, m2, tol, kernel);
+            assert_eq!(plan.modes(), (m1, m2));
+            let (x, y) = (random_points(150), random_points(150));
+            let c = random_complex(150);
+
+            let f = plan.type1(&x, &y, &c);
+            let k1: Vec<f64> = modes(m1).collect();
+            let k2: Vec<f64> = modes(m2).collect();
+            let expected = DMatrix::from_fn(m1, m2, |i, j| {
+                (0..150).map(|p| c[p] * Complex::from_polar(1.0, -(k1[i] * x[p] + k2[j] * y[p]))).sum::<Complex<f64>>()
+            });
+            assert!(relative_error(f.iter(), expected.iter()) < 10.0 * tol);
+
+            let coeffs = DMatrix::from_vec(m1, m2, random_complex(m1 * m2));
+            let values = plan.type2(&x, &y, &coeffs);
+            let expected: Vec<Complex<f64>> = (0..150)
+                .map(|p| {
+                    let mut sum = Complex::new(0.0, 0.0);
+                    for i in 0..m1 {
+                        for j in 0..m2 {
+                            sum += coeffs[(i, j)] * Complex::from_polar(1.0, k1[i] * x[p] + k2[j] * y[p]);
+                        }
+                    }
+                    sum
+                })
+                .collect();
+            assert!(relative_error(&values, &expected) < 10.0 * tol);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests_poisson {
+    use signal_transforms::poisson::{poisson_solve_dirichlet, poisson_solve_neumann};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    fn assert_matrices_close(a: &DMatrix<f32>, b: &DMatrix<f32>, epsilon: f32) {
+        let max_diff = (a - b).abs().max();
+        assert!(max_diff < epsilon, "max difference = {}", max_diff);
+    }
+
+    /// 五点差分拉普拉斯算子，`ghost` 给出越界一个像素时的取值
+    fn laplacian(u: &DMatrix<f32>, ghost: f32) -> DMatrix<f32> {
+        let (rows, cols) = u.shape();
+        let get = |i: isize, j: isize, ci: usize, cj: usize| {
+            if i < 0 || j < 0 || i >= rows as isize || j >= cols as isize {
+                ghost * u[(ci, cj)]
+            } else {
+                u[(i as usize, j as usize)]
+            }
+        };
+        DMatrix::from_fn(rows, cols, |i, j| {
+            let (ii, jj) = (i as isize, j as isize);
+            get(ii - 1, jj, i, j) + get(ii + 1, jj, i, j) + get(ii, jj - 1, i, j) + get(ii, jj + 1, i, j)
+                - 4.0 * u[(i, j)]
+        })
+    }
+
+    fn random_matrix(rows: usize, cols: usize) -> DMatrix<f32> {
+        let mut rng = rand::thread_rng();
+        DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-1.0..=1.0))
+    }
+
+    #[test]
+    fn test_poisson_neumann() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let rows = rng.gen_range(2..=16);
+            let cols = rng.gen_range(2..=16);
+            let mut u = random_matrix(rows, cols);
+            let mean = u.mean();
+            u.add_scalar_mut(-mean);
+
+            let f = laplacian(&u, 1.0);
+            let res = poisson_solve_neumann(&f);
+            assert_matrices_close(&u, &res, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_poisson_neumann_zero_mean() {
+        // f 的均值会被丢弃，解的均值为 0
+        let f = random_matrix(8, 12).add_scalar(3.0);
+        let res = poisson_solve_neumann(&f);
+        assert!(res.mean().abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_poisson_dirichlet() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let rows = rng.gen_range(1..=16);
+            let cols = rng.gen_range(1..=16);
+            let u = random_matrix(rows, cols);
+
+            let f = laplacian(&u, -1.0);
+            let res = poisson_solve_dirichlet(&f);
+            assert_matrices_close(&u, &res, 1e-3);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests_registration {
+    use signal_transforms::fft::Fft2D;
+    use signal_transforms::registration::{phase_correlate, phase_correlate_with};
+    use signal_transforms::window::Window;
+    use nalgebra::{Complex, DMatrix};
+    use rand::Rng;
+    use std::f64::consts::PI;
+
+    /// 平滑的随机图像：若干个随机高斯斑点
+    fn random_image(rows: usize, cols: usize) -> DMatrix<f64> {
+        let mut rng = rand::thread_rng();
+        let blobs: Vec<(f64, f64, f64, f64)> = (0..rows * cols / 32).map(|_| {
+            (rng.gen_range(0.0..rows as f64), rng.gen_range(0.0..cols as f64), rng.gen_range(1.0..3.0), rng.gen_range(0.5..2.0))
+        }).collect();
+        DMatrix::from_fn(rows, cols, |i, j| {
+            blobs.iter().map(|&(r, c, s, a)| {
+                let d2 = (i as f64 - r).powi(2) + (j as f64 - c).powi(2);
+                a * (-d2 / (2.0 * s * s)).exp()
+            }).sum()
+        })
+    }
+
+    /// 用傅里叶平移定理做循环的亚像素平移
+    fn fourier_shift(image: &DMatrix<f64>, dy: f64, dx: f64) -> DMatrix<f64> {
+        let (rows, cols) = image.shape();
+        let fft = Fft2D::new(rows, cols);
+        let spectrum = fft.fft_2d(&image.map(|v| Complex::new(v, 0.0)));
+        let freq = |k: usize, n: usize| if k > n / 2 { k as f64 - n as f64 } else { k as f64 } / n as f64;
+        let shifted = DMatrix::from_fn(rows, cols, |u, v| {
+            spectrum[(u, v)] * Complex::from_polar(1.0, -2.0 * PI * (freq(u, rows) * dy + freq(v, cols) * dx))
+        });
+        fft.ifft_2d(&shifted).map(|v| v.re)
+    }
+
+    #[test]
+    fn test_fft_2d() {
+        let mut rng = rand::thread_rng();
+        for (rows, cols) in [(1, 1), (3, 5), (8, 8), (6, 17)] {
+            let fft = Fft2D::<f64>::new(rows, cols);
+            let input = DMatrix::from_fn(rows, cols, |_, _| Complex::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0)));
+            let res = fft.fft_2d(&input);
+            for u in 0..rows {
+                for v in 0..cols {
+                    let mut expected = Complex::new(0.0, 0.0);
+                    for i in 0..rows {
+                        for j in 0..cols {
+                            let angle = -2.0 * PI * ((u * i) as f64 / rows as f64 + (v * j) as f64 / cols as f64);
+                            expected += input[(i, j)] * Complex::from_polar(1.0, angle);
+                        }
+                    }
+                    assert!((res[(u, v)] - expected).norm() < 1e-9);
+                }
+            }
+            let back = fft.ifft_2d(&res);
+            assert!(back.iter().zip(input.iter()).all(|(a, b)| (a - b).norm() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_phase_correlate_integer_shift() {
+        let a = random_image(64, 48);
+        for (dy, dx) in [(0, 0), (5, -3), (-11, 7), (20, 15)] {
+            let b = DMatrix::from_fn(64, 48, |i, j| {
+                a[(((i as isize - dy).rem_euclid(64)) as usize, ((j as isize - dx).rem_euclid(48)) as usize)]
+            });
+            let res = phase_correlate_with(&a, &b, Window::Rectangular);
+            assert!((res.row_shift - dy as f64).abs() < 1e-6, "{:?}", res);
+            assert!((res.col_shift - dx as f64).abs() < 1e-6, "{:?}", res);
+            assert!(res.peak > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_phase_correlate_subpixel() {
+        let a = random_image(64, 64);
+        for (dy, dx) in [(2.3, -4.6), (-7.5, 1.2), (0.4, 0.0)] {
+            let b = fourier_shift(&a, dy, dx);
+            let res = phase_correlate_with(&a, &b, Window::Rectangular);
+            assert!((res.row_shift - dy).abs() < 0.05, "{:?}", res);
+            assert!((res.col_shift - dx).abs() < 0.05, "{:?}", res);
+        }
+    }
+
+    #[test]
+    fn test_phase_correlate_windowed_crop() {
+        // 从大图中裁出两块，边缘不再循环，需要加窗
+        let scene = random_image(128, 128);
+        for (dy, dx) in [(3.3, -2.6), (-6.5, 4.2)] {
+            let shifted = fourier_shift(&scene, dy, dx);
+            let a = scene.view((32, 32), (64, 64)).into_owned();
+            let b = shifted.view((32, 32), (64, 64)).into_owned();
+            let res = phase_correlate(&a, &b);
+            assert!((res.row_shift - dy).abs() < 0.25, "{:?}", res);
+            assert!((res.col_shift - dx).abs() < 0.25, "{:?}", res);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "images must not be empty")]
+    fn test_phase_correlate_empty() {
+        let empty = DMatrix::<f64>::zeros(0, 8);
+        phase_correlate(&empty, &empty);
+    }
+
+    #[test]
+    fn test_window() {
+        let hann = Window::Hann.coefficients::<f64>(5);
+        assert!(hann.iter().zip([0.0, 0.5, 1.0, 0.5, 0.0]).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert_eq!(Window::Hamming.coefficients::<f64>(1), vec![1.0]);
+        assert!(Window::Rectangular.coefficients::<f32>(4).iter().all(|&v| v == 1.0));
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests_serde {
+    use signal_transforms::dct::{Dct, Dct2D, Dct4x4};
+    use signal_transforms::dst::{Dst, Dst2D};
+    use nalgebra::{DMatrix, Matrix4};
+
+    fn sample_matrix() -> Vec<f32> {
+        vec![
+            52.0, 55.0, 61.0, 66.0, 70.0, 61.0,
+            70.0, 61.0, 64.0, 73.0, 63.0, 59.0,
+            63.0, 59.0, 55.0, 90.0, 67.0, 61.0,
+            67.0, 61.0, 68.0, 104.0, 52.0, 55.0,
+        ]
+    }
+
+    #[test]
+    fn test_serde_dct() {
+        let dct = Dct::new(6);
+        let json = serde_json::to_string(&dct).unwrap();
+        let dct2: Dct = serde_json::from_str(&json).unwrap();
+
+        let input = DMatrix::from_row_slice(1, 6, &sample_matrix()[..6]);
+        assert_eq!(dct.dct_1d(&input), dct2.dct_1d(&input));
+        assert_eq!(dct.idct_1d(&input), dct2.idct_1d(&input));
+    }
+
+    #[test]
+    fn test_serde_dct_2d() {
+        let dct = Dct2D::new(4, 6);
+        let json = serde_json::to_string(&dct).unwrap();
+        let dct2: Dct2D = serde_json::from_str(&json).unwrap();
+
+        let input = DMatrix::from_row_slice(4, 6, &sample_matrix());
+        assert_eq!(dct.dct_2d(&input), dct2.dct_2d(&input));
+        assert_eq!(dct.idct_2d(&input), dct2.idct_2d(&input));
+    }
+
+    #[test]
+    fn test_serde_dct_4x4() {
+        let dct = Dct4x4::new();
+        let json = serde_json::to_string(&dct).unwrap();
+        let dct2: Dct4x4 = serde_json::from_str(&json).unwrap();
+
+        let input = Matrix4::from_row_slice(&sample_matrix()[..16]);
+        assert_eq!(dct.dct_2d(&input), dct2.dct_2d(&input));
+    }
+
+    #[test]
+    fn test_serde_dst() {
+        let dst = Dst::new(6);
+        let dst2: Dst = serde_json::from_str(&serde_json::to_string(&dst).unwrap()).unwrap();
+        let input = DMatrix::from_row_slice(1, 6, &sample_matrix()[..6]);
+        assert_eq!(dst.dst_1d(&input), dst2.dst_1d(&input));
+
+        let dst = Dst2D::new(4, 6);
+        let dst2: Dst2D = serde_json::from_str(&serde_json::to_string(&dst).unwrap()).unwrap();
+        let input = DMatrix::from_row_slice(4, 6, &sample_matrix());
+        assert_eq!(dst.dst_2d(&input), dst2.dst_2d(&input));
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests_swt {
+    use signal_transforms::dwt::{wavedec, Dwt, Dwt2D, ExtensionMode};
+    use signal_transforms::swt::{iswt, iswt2, swt, swt2, swt_max_level};
+    use signal_transforms::wavelet::Wavelet;
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    fn random_signal(size: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect()
+    }
+
+    fn assert_close(a: &[f64], b: &[f64], tolerance: f64) {
+        assert_eq!(a.len(), b.len());
+        for (u, v) in a.iter().zip(b) {
+            assert!((u - v).abs() < tolerance, "{} vs {}", u, v);
+        }
+    }
+
+    fn energy(x: &[f64]) -> f64 {
+        x.iter().map(|v| v * v).sum()
+    }
+
+    const WAVELETS: [&str; 9] = ["haar", "db2", "db6", "sym5", "coif2", "bior2.2", "bior4.4", "rbio3.5", "bior6.8"];
+
+    #[test]
+    fn test_swt_haar() {
+        // pywt.swt([1, 2, 3, 4], 'haar', level=1)
+        let levels = swt(&[1.0, 2.0, 3.0, 4.0], &Wavelet::haar(), 1);
+        let s = core::f64::consts::FRAC_1_SQRT_2;
+        assert_close(&levels[0].0, &[3.0 * s, 5.0 * s, 7.0 * s, 5.0 * s], 1e-12);
+        assert_close(&levels[0].1, &[-s, -s, -s, 3.0 * s], 1e-12);
+        assert_eq!(swt_max_level(48), 4);
+        assert_eq!(swt_max_level(7), 0);
+    }
+
+    #[test]
+    fn test_swt_matches_dwt() {
+        let size = 64;
+        for name in WAVELETS {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            let x = random_signal(size);
+            let levels = swt(&x, &wavelet, 3);
+            assert_eq!(levels.len(), 3);
+            // 第一层的偶数样本是周期化 DWT，奇数样本是平移一位后的 DWT
+            let dwt = Dwt::<f64>::new(size, &wavelet);
+            let mut shifted = x.clone();
+            shifted.rotate_left(1);
+            for (offset, signal) in [(0, &x), (1, &shifted)] {
+                let (a, d) = dwt.dwt(signal);
+                let (swt_a, swt_d) = &levels[2];
+                assert_close(&swt_a.iter().skip(offset).step_by(2).copied().collect::<Vec<_>>(), &a, 1e-10);
+                assert_close(&swt_d.iter().skip(offset).step_by(2).copied().collect::<Vec<_>>(), &d, 1e-10);
+            }
+            // 每隔 2^j 取样得到多层周期化 DWT
+            let coeffs = wavedec(&x, &wavelet, 3, ExtensionMode::Periodization);
+            let every_eighth: Vec<f64> = levels[0].0.iter().step_by(8).copied().collect();
+            assert_close(&every_eighth, &coeffs.approx, 1e-10);
+            let every_fourth: Vec<f64> = levels[1].1.iter().step_by(4).copied().collect();
+            assert_close(&every_fourth, &coeffs.details[1], 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_swt_reconstruction() {
+        for name in WAVELETS {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            for size in [8, 32, 48, 96] {
+                let x = random_signal(size);
+                for level in 1..=swt_max_level(size) {
+                    let levels = swt(&x, &wavelet, level);
+                    assert!(levels.iter().all(|(a, d)| a.len() == size && d.len() == size));
+                    assert_close(&iswt(&levels, &wavelet), &x, 1e-9);
+                }
+            }
+        }
+        let x: Vec<f32> = (0..32).map(|i| (i as f32 * 0.3).sin()).collect();
+        let y = iswt(&swt(&x, &Wavelet::daubechies(3), 4), &Wavelet::daubechies(3));
+        assert!(x.iter().zip(&y).all(|(u, v)| (u - v).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_swt_redundancy() {
+        let size = 32;
+        let x = random_signal(size);
+        for name in ["haar", "db4", "sym6", "coif1"] {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            // 正交小波每层是界为 2 的紧框架
+            let levels = swt(&x, &wavelet, 1);
+            assert!((energy(&levels[0].0) + energy(&levels[0].1) - 2.0 * energy(&x)).abs() < 1e-9);
+
+            // 平移不变：信号循环平移，系数跟着平移
+            let levels = swt(&x, &wavelet, 3);
+            let mut shifted = x.clone();
+            shifted.rotate_right(5);
+            for ((a, d), (sa, sd)) in levels.iter().zip(swt(&shifted, &wavelet, 3)) {
+                let (mut a, mut d) = (a.clone(), d.clone());
+                a.rotate_right(5);
+                d.rotate_right(5);
+                assert_close(&a, &sa, 1e-10);
+                assert_close(&d, &sd, 1e-10);
+            }
+
+            // 重构取偶数和奇数两组样本各自重构的平均
+            let (mut a, mut d) = levels[2].clone();
+            let mut rng = rand::thread_rng();
+            a.iter_mut().chain(d.iter_mut()).for_each(|v| *v += rng.gen_range(-0.1..0.1));
+            let dwt = Dwt::<f64>::new(size, &wavelet);
+            let pick = |v: &[f64], offset| v.iter().skip(offset).step_by(2).copied().collect::<Vec<f64>>();
+            let even = dwt.idwt(&pick(&a, 0), &pick(&d, 0));
+            let mut odd = dwt.idwt(&pick(&a, 1), &pick(&d, 1));
+            odd.rotate_right(1);
+            let average: Vec<f64> = even.iter().zip(&odd).map(|(u, v)| (u + v) / 2.0).collect();
+            assert_close(&iswt(&[(a, d)], &wavelet), &average, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_swt2() {
+        let mut rng = rand::thread_rng();
+        for name in ["haar", "db3", "bior2.2", "sym4"] {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            for (row, col) in [(16, 24), (8, 8), (32, 4)] {
+                let x = DMatrix::from_fn(row, col, |_, _| rng.gen_range(-1.0..=1.0));
+                let level = swt_max_level(row).min(swt_max_level(col));
+                let levels = swt2(&x, &wavelet, level);
+                assert_eq!(levels.len(), level);
+                for subbands in &levels {
+                    for band in [&subbands.ll, &subbands.lh, &subbands.hl, &subbands.hh] {
+                        assert_eq!(band.shape(), (row, col));
+                    }
+                }
+                let y = iswt2(&levels, &wavelet);
+                assert!((&y - &x).abs().max() < 1e-9, "{} {}x{}", name, row, col);
+
+                // 偶数行、偶数列上是周期化 DWT2
+                let finest = &levels[level - 1];
+                let dwt = Dwt2D::<f64>::new(row, col, &wavelet).dwt2(&x);
+                let even = |m: &DMatrix<f64>| DMatrix::from_fn(row / 2, col / 2, |i, j| m[(2 * i, 2 * j)]);
+                for (swt_band, dwt_band) in [(&finest.ll, &dwt.ll), (&finest.lh, &dwt.lh), (&finest.hl, &dwt.hl), (&finest.hh, &dwt.hh)] {
+                    assert!((even(swt_band) - dwt_band).abs().max() < 1e-10);
+                }
+            }
+        }
+    }
+}
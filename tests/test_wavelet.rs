@@ -0,0 +1,308 @@
+#[cfg(test)]
+mod tests_wavelet {
+    use signal_transforms::dwt::{Dwt, ExtensionMode};
+    use signal_transforms::wavelet::{wavelist, Symmetry, Wavelet, WaveletFamily};
+    use rand::Rng;
+
+    /// `Σ h(k) h(k + 2m)`
+    fn autocorrelation(h: &[f64], shift: usize) -> f64 {
+        h.iter().zip(&h[shift.min(h.len())..]).map(|(a, b)| a * b).sum()
+    }
+
+    #[test]
+    fn test_wavelet_daubechies_values() {
+        // pywt 中的 db2 和 db4 分解低通滤波器
+        let db2 = [-0.12940952255126037, 0.2241438680420134, 0.8365163037378079, 0.48296291314453416];
+        let db4 = [
+            -0.010597401784997278, 0.032883011666982945, 0.030841381835986965, -0.18703481171888114,
+            -0.02798376941698385, 0.6308807679295904, 0.7148465705525415, 0.23037781330885523,
+        ];
+        for (wavelet, expected) in [(Wavelet::daubechies(2), &db2[..]), (Wavelet::daubechies(4), &db4[..])] {
+            for (a, b) in wavelet.dec_lo().iter().zip(expected) {
+                assert!((a - b).abs() < 1e-12);
+            }
+        }
+        let haar = Wavelet::haar();
+        assert_eq!(haar.name(), "haar");
+        assert_eq!(haar.dec_hi(), Wavelet::daubechies(1).dec_hi());
+        assert!((haar.dec_hi()[0] + core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_wavelet_daubechies_orthogonality() {
+        for order in 1..=20 {
+            let wavelet = Wavelet::daubechies(order);
+            assert_eq!(wavelet.filter_len(), 2 * order);
+            assert_eq!(wavelet.name(), format!("db{}", order));
+            let h = wavelet.rec_lo();
+            for m in 0..order {
+                let expected = if m == 0 { 1.0 } else { 0.0 };
+                assert!((autocorrelation(h, 2 * m) - expected).abs() < 1e-10, "db{} m={}", order, m);
+            }
+            // N 阶消失矩：高通滤波器与 k^p 正交
+            for p in 0..order as i32 {
+                let moment: f64 = wavelet.dec_hi().iter().enumerate().map(|(k, g)| g * (k as f64 / order as f64).powi(p)).sum();
+                assert!(moment.abs() < 1e-8, "db{} p={}", order, p);
+            }
+        }
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64, name: &str) {
+        assert_eq!(actual.len(), expected.len(), "{}", name);
+        for (a, b) in actual.iter().zip(expected) {
+            assert!((a - b).abs() < tolerance, "{}: {} vs {}", name, a, b);
+        }
+    }
+
+    #[test]
+    fn test_wavelet_catalogue_values() {
+        // pywt 中的分解低通滤波器
+        let sym4 = [
+            -0.07576571478927333, -0.02963552764599851, 0.49761866763201545, 0.8037387518059161,
+            0.29785779560527736, -0.09921954357684722, -0.012603967262037833, 0.0322231006040427,
+        ];
+        assert_close(Wavelet::symlet(4).dec_lo(), &sym4, 1e-12, "sym4");
+        let coif1 = [
+            -0.01565572813546454, -0.0727326195128539, 0.38486484686420286, 0.8525720202122554,
+            0.3378976624578092, -0.0727326195128539,
+        ];
+        assert_close(Wavelet::coiflet(1).dec_lo(), &coif1, 1e-15, "coif1");
+        let coif2 = [-0.0007205494453645122, -0.0018232088707029932, 0.0056114348193944995];
+        assert_close(&Wavelet::coiflet(2).dec_lo()[..3], &coif2, 1e-15, "coif2");
+
+        // bior2.2 的系数都是 1/√2 的有理倍数
+        let r = core::f64::consts::FRAC_1_SQRT_2;
+        let bior22 = Wavelet::biorthogonal(2, 2);
+        assert_close(bior22.dec_lo(), &[0.0, -r / 4.0, r / 2.0, 1.5 * r, r / 2.0, -r / 4.0], 1e-12, "bior2.2 dec_lo");
+        assert_close(bior22.rec_lo(), &[0.0, r / 2.0, r, r / 2.0, 0.0, 0.0], 1e-12, "bior2.2 rec_lo");
+        assert_close(bior22.dec_hi(), &[0.0, r / 2.0, -r, r / 2.0, 0.0, 0.0], 1e-12, "bior2.2 dec_hi");
+        let rbio22 = Wavelet::reverse_biorthogonal(2, 2);
+        assert_close(rbio22.dec_lo(), &[0.0, 0.0, r / 2.0, r, r / 2.0, 0.0], 1e-12, "rbio2.2");
+
+        let bior44 = Wavelet::biorthogonal(4, 4);
+        let dec = [
+            0.0, 0.03782845550726404, -0.023849465019556843, -0.11062440441843718, 0.37740285561283066,
+            0.8526986790088938, 0.37740285561283066, -0.11062440441843718, -0.023849465019556843, 0.03782845550726404,
+        ];
+        let rec = [
+            0.0, -0.06453888262869706, -0.04068941760916406, 0.41809227322161724, 0.7884856164055829,
+            0.41809227322161724, -0.04068941760916406, -0.06453888262869706, 0.0, 0.0,
+        ];
+        assert_close(bior44.dec_lo(), &dec, 1e-9, "bior4.4 dec_lo");
+        assert_close(bior44.rec_lo(), &rec, 1e-9, "bior4.4 rec_lo");
+        // pywt 的 bior5.5 和 bior6.8；高通滤波器由低通滤波器交替变号得到
+        let bior55_dec = [
+            0.0, 0.0, 0.03968708834740544, 0.007948108637240322, -0.05446378846823691, 0.34560528195603346,
+            0.7366601814282105, 0.34560528195603346, -0.05446378846823691, 0.007948108637240322, 0.03968708834740544, 0.0,
+        ];
+        let bior55_rec = [
+            0.013456709459118716, -0.002694966880111507, -0.13670658466432914, -0.09350469740093886,
+            0.47680326579848425, 0.8995061097486484, 0.47680326579848425, -0.09350469740093886,
+            -0.13670658466432914, -0.002694966880111507, 0.013456709459118716, 0.0,
+        ];
+        let bior68_dec = [
+            0.0, 0.0019088317364812906, -0.0019142861290887667, -0.016990639867602342, 0.01193456527972926,
+            0.04973290349094079, -0.07726317316720414, -0.09405920349573646, 0.4207962846098268, 0.8259229974584023,
+            0.4207962846098268, -0.09405920349573646, -0.07726317316720414, 0.04973290349094079, 0.01193456527972926,
+            -0.016990639867602342, -0.0019142861290887667, 0.0019088317364812906,
+        ];
+        let bior68_rec = [
+            0.0, 0.0, 0.0, 0.014426282505624435, 0.014467504896790148, -0.07872200106262882, -0.04036797903033992,
+            0.41784910915027457, 0.7589077294536541, 0.41784910915027457, -0.04036797903033992, -0.07872200106262882,
+            0.014467504896790148, 0.014426282505624435, 0.0, 0.0, 0.0, 0.0,
+        ];
+        for (name, dec, rec) in [("bior5.5", &bior55_dec[..], &bior55_rec[..]), ("bior6.8", &bior68_dec[..], &bior68_rec[..])] {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            let sign = |k: usize| if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+            let dec_hi: Vec<f64> = rec.iter().enumerate().map(|(k, v)| -sign(k) * v).collect();
+            let rec_hi: Vec<f64> = dec.iter().enumerate().map(|(k, v)| sign(k) * v).collect();
+            assert_close(wavelet.dec_lo(), dec, 1e-11, name);
+            assert_close(wavelet.rec_lo(), rec, 1e-11, name);
+            assert_close(wavelet.dec_hi(), &dec_hi, 1e-11, name);
+            assert_close(wavelet.rec_hi(), &rec_hi, 1e-11, name);
+        }
+        for (name, len) in [("bior1.3", 6), ("bior3.9", 20), ("bior5.5", 12), ("bior6.8", 18), ("dmey", 62)] {
+            assert_eq!(Wavelet::from_name(name).unwrap().filter_len(), len, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_wavelet_symlet_values() {
+        // pywt 中 sym2–sym20 分解低通滤波器的第一个系数
+        let first = [
+            -0.12940952255126037, 0.035226291882100656, -0.07576571478927333, 0.027333068345077982,
+            0.015404109327027373, 0.002681814568257878, -0.0033824159510061256, 0.0014009155259146807,
+            0.0007701598091144901, 0.00017172195069934854, 0.00011196719424656033, 6.820325263075319e-05,
+            -2.5879090265397886e-05, 9.712419737963348e-06, 1.8905771831912588e-05, 4.297343327345983e-06,
+            2.6126125564836423e-06, 5.487732768215838e-07, 3.695537474835221e-07
+        ];
+        for (order, &expected) in (2..=20).zip(&first) {
+            let first = Wavelet::symlet(order).dec_lo()[0];
+            assert!((first - expected).abs() < 1e-11, "sym{}: {} vs {}", order, first, expected);
+        }
+        let sym7 = [
+            0.002681814568257878, -0.0010473848886829163, -0.01263630340325193, 0.03051551316596357,
+            0.0678926935013727, -0.049552834937127255, 0.017441255086855827, 0.5361019170917628, 0.767764317003164,
+            0.2886296317515146, -0.14004724044296152, -0.10780823770381774, 0.004010244871533663, 0.010268176708511255
+        ];
+        assert_close(Wavelet::symlet(7).dec_lo(), &sym7, 1e-11, "sym7");
+    }
+
+    #[test]
+    fn test_wavelet_coiflet_values() {
+        // pywt 中 coif1–coif5 分解低通滤波器的第一个系数
+        let first = [
+            -0.01565572813546454, -0.0007205494453645122, -3.459977283621256e-05, -1.7849850030882614e-06,
+            -9.517657273819165e-08,
+        ];
+        for (order, &expected) in (1..=5).zip(&first) {
+            let first = Wavelet::coiflet(order).dec_lo()[0];
+            assert!((first - expected).abs() < 1e-15, "coif{}: {} vs {}", order, first, expected);
+        }
+        let coif4 = [
+            -1.7849850030882614e-06, -3.2596802368833675e-06, 3.1229875865345646e-05, 6.233903446100713e-05,
+            -0.00025997455248771324, -0.0005890207562443383, 0.0012665619292989445, 0.003751436157278457,
+            -0.00565828668661072, -0.015211731527946259, 0.025082261844864097, 0.03933442712333749,
+            -0.09622044203398798, -0.06662747426342504, 0.4343860564914685, 0.782238930920499, 0.41530840703043026,
+            -0.05607731331675481, -0.08126669968087875, 0.026682300156053072, 0.016068943964776348,
+            -0.0073461663276420935, -0.0016294920126017326, 0.0008923136685823146
+        ];
+        assert_close(Wavelet::coiflet(4).dec_lo(), &coif4, 1e-15, "coif4");
+        // coif5 的公布值只在约 1e-8 内满足 coiflet 方程，但仍须逐位一致
+        let coif5 = [
+            -9.517657273819165e-08, -1.6744288576823017e-07, 2.0637618513646814e-06, 3.7346551751414047e-06,
+            -2.1315026809955787e-05, -4.134043227251251e-05, 0.00014054114970203437, 0.00030225958181306315,
+            -0.0006381313430451114, -0.0016628637020130838, 0.0024333732126576722, 0.006764185448053083,
+            -0.009164231162481846, -0.01976177894257264, 0.03268357426711183, 0.0412892087501817, -0.10557420870333893,
+            -0.06203596396290357, 0.4379916261718371, 0.7742896036529562, 0.4215662066908515, -0.05204316317624377,
+            -0.09192001055969624, 0.02816802897093635, 0.023408156785839195, -0.010131117519849788,
+            -0.004159358781386048, 0.0021782363581090178, 0.00035858968789573785, -0.00021208083980379827
+        ];
+        assert_close(Wavelet::coiflet(5).dec_lo(), &coif5, 1e-15, "coif5");
+    }
+
+    #[test]
+    fn test_wavelet_discrete_meyer_values() {
+        let dmey = Wavelet::discrete_meyer();
+        let h = dmey.dec_lo();
+        // pywt 中的 dmey：第一个系数为 0，其余关于第 31 个系数对称
+        assert_eq!(h[0], 0.0);
+        assert!((h[31] - 0.7445855923188063).abs() < 1e-9);
+        assert!((h[30] - 0.44459300275757724).abs() < 1e-9);
+        assert!((1..31).all(|k| h[31 - k] == h[31 + k]));
+        assert!((h.iter().sum::<f64>() - core::f64::consts::SQRT_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_wavelet_names() {
+        let names = wavelist();
+        assert_eq!(names.len(), 1 + 20 + 19 + 5 + 15 + 15 + 1);
+        for name in &names {
+            let wavelet = Wavelet::from_name(name).unwrap();
+            assert_eq!(wavelet.name(), name);
+            let (family, symmetry) = match name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+                "haar" => (WaveletFamily::Haar, Symmetry::Asymmetric),
+                "db" => (WaveletFamily::Daubechies, Symmetry::Asymmetric),
+                "sym" => (WaveletFamily::Symlets, Symmetry::NearSymmetric),
+                "coif" => (WaveletFamily::Coiflets, Symmetry::NearSymmetric),
+                "bior" => (WaveletFamily::Biorthogonal, Symmetry::Symmetric),
+                "rbio" => (WaveletFamily::ReverseBiorthogonal, Symmetry::Symmetric),
+                _ => (WaveletFamily::DiscreteMeyer, Symmetry::Symmetric),
+            };
+            assert_eq!(wavelet.family(), family, "{}", name);
+            assert_eq!(wavelet.symmetry(), symmetry, "{}", name);
+        }
+        assert_eq!(Wavelet::from_name("db4"), Some(Wavelet::daubechies(4)));
+        assert_eq!(Wavelet::from_name("coif3").unwrap().vanishing_moments_phi(), Some(5));
+        assert_eq!(Wavelet::from_name("coif5").unwrap().filter_len(), 30);
+        for name in ["", "db", "db0", "db21", "db04", "sym1", "coif0", "coif6", "bior2.3", "bior2", "rbio7.7", "meyer"] {
+            assert!(Wavelet::from_name(name).is_none(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_wavelet_biorthogonality() {
+        for name in wavelist() {
+            let wavelet = Wavelet::from_name(&name).unwrap();
+            if wavelet.family() == WaveletFamily::DiscreteMeyer {
+                // 公布的 FIR 近似 Σh² ≈ 1.0022，只能近似正交
+                assert!(wavelet.is_orthogonal(1e-2) && !wavelet.is_biorthogonal(1e-3));
+                continue;
+            }
+            // 公布的 coif5 只在约 1e-8 内满足正交条件
+            let tolerance = if name == "coif5" { 1e-8 } else { 1e-10 };
+            assert!(wavelet.is_biorthogonal(tolerance), "{}", name);
+            let biorthogonal = matches!(wavelet.family(), WaveletFamily::Biorthogonal | WaveletFamily::ReverseBiorthogonal);
+            // bior1.1 与 haar 相同
+            assert_eq!(wavelet.is_orthogonal(tolerance), !biorthogonal || name.ends_with("1.1"), "{}", name);
+            assert!((wavelet.dec_lo().iter().sum::<f64>() - core::f64::consts::SQRT_2).abs() < 1e-10);
+
+            // 分解高通滤波器恰好消去次数低于消失矩阶数的多项式（相对误差）
+            let psi = wavelet.vanishing_moments_psi().unwrap();
+            let centre = wavelet.filter_len() as f64 / 2.0;
+            let moment = |p: usize| {
+                let terms = wavelet.dec_hi().iter().enumerate().map(|(k, g)| g * (k as f64 - centre).powi(p as i32));
+                terms.clone().sum::<f64>().abs() / terms.map(f64::abs).sum::<f64>()
+            };
+            for p in 0..psi {
+                assert!(moment(p) < 10.0 * tolerance, "{} p={}", name, p);
+            }
+            // 高阶时抵消太严重，无法判断下一阶矩是否非零
+            if psi <= 10 {
+                assert!(moment(psi) > 1e-6, "{} has more than {} vanishing moments", name, psi);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wavelet_reconstruction() {
+        let mut rng = rand::thread_rng();
+        for name in wavelist() {
+            let wavelet = Wavelet::from_name(&name).unwrap();
+            // dmey 和 coif5 只近似正交，见 test_wavelet_biorthogonality
+            let tolerance = match name.as_str() {
+                "dmey" => 1e-2,
+                "coif5" => 1e-7,
+                _ => 1e-9,
+            };
+            for size in [16, 33] {
+                let x: Vec<f64> = (0..size).map(|_| rng.gen_range(-1.0..=1.0)).collect();
+                for mode in [ExtensionMode::Zero, ExtensionMode::Symmetric, ExtensionMode::Periodic, ExtensionMode::Periodization] {
+                    let dwt = Dwt::<f64>::with_mode(size, &wavelet, mode);
+                    let (a, d) = dwt.dwt(&x);
+                    for (u, v) in x.iter().zip(dwt.idwt(&a, &d)) {
+                        assert!((u - v).abs() < tolerance, "{} size={} {:?}", name, size, mode);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_wavelet_custom() {
+        let db3 = Wavelet::daubechies(3);
+        let custom = Wavelet::from_filters("mine", db3.dec_lo(), db3.dec_hi(), db3.rec_lo(), db3.rec_hi()).unwrap();
+        assert_eq!(custom.name(), "mine");
+        assert_eq!(custom.family(), WaveletFamily::Custom);
+        assert_eq!(custom.symmetry(), Symmetry::Unknown);
+        assert_eq!(custom.vanishing_moments_psi(), None);
+        assert!(custom.is_orthogonal(1e-12));
+
+        let bior = Wavelet::biorthogonal(3, 5);
+        let custom = Wavelet::from_filters("b", bior.dec_lo(), bior.dec_hi(), bior.rec_lo(), bior.rec_hi()).unwrap();
+        assert!(custom.is_biorthogonal(1e-12) && !custom.is_orthogonal(1e-12));
+
+        let from_lowpass = Wavelet::from_lowpass("lp", db3.dec_lo()).unwrap();
+        assert_eq!(from_lowpass.dec_hi(), db3.dec_hi());
+        assert_eq!(from_lowpass.rec_hi(), db3.rec_hi());
+
+        // 扰动、长度不一致或奇数长度都会被拒绝
+        let mut perturbed = db3.dec_lo().to_vec();
+        perturbed[2] += 1e-4;
+        assert!(Wavelet::from_filters("x", &perturbed, db3.dec_hi(), db3.rec_lo(), db3.rec_hi()).is_none());
+        assert!(Wavelet::from_lowpass("x", &perturbed).is_none());
+        assert!(Wavelet::from_filters("x", db3.dec_lo(), &db3.dec_hi()[1..], db3.rec_lo(), db3.rec_hi()).is_none());
+        assert!(Wavelet::from_lowpass("x", &[0.5, 0.5, 0.5]).is_none());
+        assert!(Wavelet::from_lowpass("x", &[]).is_none());
+    }
+}
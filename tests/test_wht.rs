@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests_wht {
+    use signal_transforms::wht::{Wht, Wht2D, WhtOrdering};
+    use nalgebra::DMatrix;
+    use rand::Rng;
+
+    /// 自然序 Hadamard 矩阵的元素 (-1)^{popcount(k & n)}
+    fn hadamard(k: usize, n: usize) -> f64 {
+        if (k & n).count_ones().is_multiple_of(2) { 1.0 } else { -1.0 }
+    }
+
+    /// 基函数的符号变化次数
+    fn sign_changes(row: &[f64]) -> usize {
+        row.windows(2).filter(|w| w[0] * w[1] < 0.0).count()
+    }
+
+    /// 对单位脉冲做变换，得到变换矩阵的各行
+    fn basis(wht: &Wht<f64>) -> Vec<Vec<f64>> {
+        let n = wht.len();
+        let columns: Vec<Vec<f64>> = (0..n)
+            .map(|j| wht.wht(&(0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect::<Vec<_>>()))
+            .collect();
+        (0..n).map(|k| columns.iter().map(|c| c[k]).collect()).collect()
+    }
+
+    #[test]
+    fn test_wht_natural() {
+        let mut rng = rand::thread_rng();
+        for n in [1, 2, 8, 64] {
+            let wht = Wht::<f64>::new(n, WhtOrdering::Natural);
+            let input: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..=1.0)).collect();
+            let res = wht.wht(&input);
+            for (k, &v) in res.iter().enumerate() {
+                let expected: f64 = input.iter().enumerate().map(|(i, x)| x * hadamard(k, i)).sum::<f64>() / (n as f64).sqrt();
+                assert!((v - expected).abs() < 1e-12);
+            }
+            // 自然序下自逆
+            assert!(wht.wht(&res).iter().zip(&input).all(|(a, b)| (a - b).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_wht_orderings() {
+        let n = 32;
+        let sequency = basis(&Wht::new(n, WhtOrdering::Sequency));
+        for (s, row) in sequency.iter().enumerate() {
+            assert_eq!(sign_changes(row), s);
+        }
+        let dyadic = basis(&Wht::new(n, WhtOrdering::Dyadic));
+        for (p, row) in dyadic.iter().enumerate() {
+            // Paley 序 p 的符号变化次数是 p 的格雷码逆
+            let mut s = p;
+            let mut shift = p >> 1;
+            while shift > 0 {
+                s ^= shift;
+                shift >>= 1;
+            }
+            assert_eq!(sign_changes(row), s);
+        }
+
+        let mut rng = rand::thread_rng();
+        let input: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..=1.0)).collect();
+        for ordering in [WhtOrdering::Sequency, WhtOrdering::Dyadic] {
+            let wht = Wht::new(n, ordering);
+            let back = wht.iwht(&wht.wht(&input));
+            assert!(back.iter().zip(&input).all(|(a, b)| (a - b).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_wht_int() {
+        let mut rng = rand::thread_rng();
+        for ordering in [WhtOrdering::Natural, WhtOrdering::Sequency, WhtOrdering::Dyadic] {
+            let wht = Wht::<f64>::new(256, ordering);
+            let input: Vec<i64> = (0..256).map(|_| rng.gen_range(-1000..=1000)).collect();
+            let mut data = input.clone();
+            wht.wht_int_inplace(&mut data);
+            // 与浮点版本只差 √N 的缩放
+            let float = wht.wht(&input.iter().map(|&v| v as f64).collect::<Vec<_>>());
+            assert!(data.iter().zip(&float).all(|(&a, &b)| (a as f64 - b * 16.0).abs() < 1e-9));
+            wht.iwht_int_inplace(&mut data);
+            assert_eq!(data, input);
+        }
+    }
+
+    #[test]
+    fn test_wht_2d() {
+        let mut rng = rand::thread_rng();
+        for ordering in [WhtOrdering::Natural, WhtOrdering::Sequency, WhtOrdering::Dyadic] {
+            let (rows, cols) = (8, 16);
+            let wht = Wht2D::<f64>::new(rows, cols, ordering);
+            let input = DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-1.0..=1.0));
+            let res = wht.wht_2d(&input);
+
+            // 与先行后列的一维变换一致
+            let (row_plan, col_plan) = (Wht::new(cols, ordering), Wht::new(rows, ordering));
+            let mut expected = input.clone();
+            for i in 0..rows {
+                let row: Vec<f64> = expected.row(i).iter().copied().collect();
+                for (j, v) in row_plan.wht(&row).into_iter().enumerate() {
+                    expected[(i, j)] = v;
+                }
+            }
+            for j in 0..cols {
+                let col: Vec<f64> = expected.column(j).iter().copied().collect();
+                for (i, v) in col_plan.wht(&col).into_iter().enumerate() {
+                    expected[(i, j)] = v;
+                }
+            }
+            assert!((&res - &expected).amax() < 1e-12);
+            assert!((wht.iwht_2d(&res) - &input).amax() < 1e-12);
+
+            let int_input = DMatrix::from_fn(rows, cols, |_, _| rng.gen_range(-255i64..=255));
+            let mut data = int_input.clone();
+            wht.wht_2d_int_inplace(&mut data);
+            wht.iwht_2d_int_inplace(&mut data);
+            assert_eq!(data, int_input);
+        }
+    }
+}